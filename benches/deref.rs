@@ -0,0 +1,41 @@
+//! Compares dereferencing a `SelfMonad` with a function pointer projection to calling the function
+//! directly. Run with `cargo bench --bench deref`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use self_monad::SelfMonad;
+
+const ITERATIONS: u32 = 10_000_000;
+
+fn middle(bytes: &[u8; 16]) -> &[u8] {
+    &bytes[1..15]
+}
+
+fn measure<G: FnMut() -> usize>(mut g: G) -> Duration {
+    let start = Instant::now();
+    let mut total = 0;
+    for _ in 0..ITERATIONS {
+        total += black_box(g());
+    }
+    black_box(total);
+    start.elapsed()
+}
+
+fn main() {
+    let owner = *b"0123456789abcdef";
+    // The owner and the function side by side, laid out like the monad stores them
+    let pair = (owner, middle as fn(&[u8; 16]) -> &[u8]);
+    let m = SelfMonad::new(owner, middle as fn(&[u8; 16]) -> &[u8]);
+    // Both paths have to project the same value for the comparison to mean anything
+    assert_eq!(middle(&owner).len(), m.len());
+
+    let direct = measure(|| {
+        let (owner, func) = black_box(&pair);
+        func(owner).len()
+    });
+    let monad = measure(|| black_box(&m).len());
+    let per_call = |d: Duration| d.as_secs_f64() * 1e9 / f64::from(ITERATIONS);
+    println!("direct call: {:.3} ns/iter", per_call(direct));
+    println!("deref:       {:.3} ns/iter", per_call(monad));
+}
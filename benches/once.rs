@@ -0,0 +1,33 @@
+//! Compares `SelfMonadOnce::as_ref` with `as_ref_unchecked` on monads that are dereferenced right
+//! after construction. Run with `cargo bench --bench once`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use self_monad::SelfMonadOnce;
+
+const ITERATIONS: u32 = 10_000_000;
+
+fn measure<G: FnMut(u32) -> usize>(mut g: G) -> Duration {
+    let start = Instant::now();
+    let mut total = 0;
+    for i in 0..ITERATIONS {
+        total += g(i);
+    }
+    black_box(total);
+    start.elapsed()
+}
+
+fn main() {
+    let checked = measure(|i| {
+        let m = SelfMonadOnce::new(black_box([i; 4]), |a| &a[1..3]);
+        m.as_ref().len()
+    });
+    let unchecked = measure(|i| {
+        let m = SelfMonadOnce::new(black_box([i; 4]), |a| &a[1..3]);
+        unsafe { m.as_ref_unchecked() }.len()
+    });
+    let per_call = |d: Duration| d.as_secs_f64() * 1e9 / f64::from(ITERATIONS);
+    println!("as_ref:           {:.3} ns/iter", per_call(checked));
+    println!("as_ref_unchecked: {:.3} ns/iter", per_call(unchecked));
+}
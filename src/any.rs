@@ -0,0 +1,41 @@
+use crate::{SelfMonad, SelfMonadMut, SelfMonadOnce};
+
+/// Object-safe view of a monad as its projected value, so monads over different owners and
+/// projections can be stored together as `Box<dyn AnySelfMonad<V>>`.
+pub trait AnySelfMonad<V: ?Sized> {
+    fn value(&self) -> &V;
+}
+
+impl<O, V: ?Sized, F: FnOnce(&O) -> &V> AnySelfMonad<V> for SelfMonadOnce<O, V, F> {
+    fn value(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> AnySelfMonad<V> for SelfMonad<O, V, F> {
+    fn value(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> AnySelfMonad<V> for SelfMonadMut<O, V, F> {
+    fn value(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AnySelfMonad, SelfMonad, SelfMonadMut, SelfMonadOnce};
+
+    #[test]
+    fn any_boxed() {
+        let monads: Vec<Box<dyn AnySelfMonad<str>>> = vec![
+            Box::new(SelfMonad::new(String::from("hello"), |s| &s[0..2])),
+            Box::new(SelfMonadMut::new((1, String::from("world")), |t| t.1.as_str())),
+            Box::new(SelfMonadOnce::new(vec![String::from("once")], |v| v[0].as_str()))
+        ];
+        let values: Vec<&str> = monads.iter().map(|m| m.value()).collect();
+        assert_eq!(vec!["he", "world", "once"], values);
+    }
+}
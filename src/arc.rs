@@ -0,0 +1,120 @@
+use core::marker::PhantomData;
+use core::ops::Deref;
+use alloc::sync::Arc;
+
+use crate::SelfMonadOwner;
+
+/// Projects an owner shared through an `Arc`, so clones are cheap and can be sent to other threads.
+pub struct SelfMonadArc<O, V: ?Sized, F> {
+    owner: Arc<O>,
+    func: Arc<F>,
+    phantom: PhantomData<fn() -> V>
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> SelfMonadArc<O, V, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadArc {
+            owner: Arc::new(owner),
+            func: Arc::new(func),
+            phantom: PhantomData
+        }
+    }
+
+    /// Returns the address of the shared owner, the same for all clones.
+    pub fn owner_ptr(&self) -> *const O {
+        Arc::as_ptr(&self.owner)
+    }
+
+    /// Runs the projection and returns the address of the projected value, for identity checks.
+    pub fn value_ptr(&self) -> *const V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> Deref for SelfMonadArc<O, V, F> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> AsRef<V> for SelfMonadArc<O, V, F> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        (self.func)(&self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F> Clone for SelfMonadArc<O, V, F> {
+    fn clone(&self) -> Self {
+        SelfMonadArc {
+            owner: Arc::clone(&self.owner),
+            func: Arc::clone(&self.func),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOwner<Arc<O>> for SelfMonadArc<O, V, F> {
+    fn owner(&self) -> &Arc<O> {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut Arc<O> {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> Arc<O> {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadArc, SelfMonadOwner};
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn arc_deref() {
+        let m = SelfMonadArc::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        assert_eq!("he", m.as_ref());
+    }
+
+    #[test]
+    fn arc_ptr() {
+        let m1 = SelfMonadArc::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = m1.clone();
+        assert_eq!(m1.owner_ptr(), m2.owner_ptr());
+        assert_eq!(m1.value_ptr(), m2.value_ptr());
+
+        let m3 = SelfMonadArc::new(String::from("hello"), |s| &s[0..2]);
+        assert_ne!(m1.owner_ptr(), m3.owner_ptr());
+        assert_ne!(m1.value_ptr(), m3.value_ptr());
+    }
+
+    #[test]
+    fn arc_clone_shares_owner() {
+        let m1 = SelfMonadArc::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = m1.clone();
+        assert!(Arc::ptr_eq(m1.owner(), m2.owner()));
+        assert_eq!(2, Arc::strong_count(m1.owner()));
+    }
+
+    #[test]
+    fn arc_threads() {
+        let m = SelfMonadArc::new(String::from("hello"), |s| &s[0..2]);
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let m = m.clone();
+                thread::spawn(move || m.to_string())
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!("he", handle.join().unwrap());
+        }
+    }
+}
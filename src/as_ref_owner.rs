@@ -0,0 +1,34 @@
+use crate::SelfMonadOwner;
+
+/// Borrows the owner of a monad.
+///
+/// `AsRef<V>` already borrows the projected value, so generic code asking for the owner bounds on
+/// this trait instead. Every `SelfMonadOwner` implements it.
+pub trait AsRefOwner<O> {
+    fn as_ref_owner(&self) -> &O;
+}
+
+impl<O, T: SelfMonadOwner<O>> AsRefOwner<O> for T {
+    fn as_ref_owner(&self) -> &O {
+        self.owner()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AsRefOwner, SelfMonad, SelfMonadMut, SelfMonadOnce};
+
+    fn owner_len<M: AsRefOwner<String> + AsRef<str>>(m: &M) -> (usize, usize) {
+        (m.as_ref_owner().len(), m.as_ref().len())
+    }
+
+    #[test]
+    fn as_ref_owner() {
+        let once = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..3]);
+        let mut_m = SelfMonadMut::new(String::from("hello"), |s| &s[0..4]);
+        assert_eq!((5, 2), owner_len(&once));
+        assert_eq!((5, 3), owner_len(&m));
+        assert_eq!((5, 4), owner_len(&mut_m));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{SelfMonad, SelfMonadOwner};
+
+/// Holds an owner until its projection is chosen.
+pub struct SelfMonadBuilder<O> {
+    owner: O
+}
+
+impl<O> SelfMonadBuilder<O> {
+    pub fn new(owner: O) -> Self {
+        SelfMonadBuilder { owner }
+    }
+
+    /// Finishes the monad with the projection `func`.
+    pub fn project<V: ?Sized, F: Fn(&O) -> &V>(self, func: F) -> SelfMonad<O, V, F> {
+        SelfMonad::new(self.owner, func)
+    }
+
+    /// Finishes the monad with the mutable projection `func`.
+    pub fn project_mut<V: ?Sized, F: Fn(&mut O) -> &mut V>(self, func: F) -> SelfMonad<O, V, F> {
+        SelfMonad::new_mut(self.owner, func)
+    }
+}
+
+impl<O> SelfMonadOwner<O> for SelfMonadBuilder<O> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadBuilder, SelfMonadOwner};
+
+    #[test]
+    fn builder_project() {
+        let mut builder = SelfMonadBuilder::new(String::from("hello"));
+        builder.owner_mut().push_str(" world");
+
+        let m = builder.project(|s| &s[6..]);
+        assert_eq!("world", &*m);
+    }
+
+    #[test]
+    fn builder_project_mut() {
+        let builder = SelfMonadBuilder::new(vec![1, 2, 3]);
+        let mut m = builder.project_mut(|v| &mut v[1..]);
+        m.as_mut()[0] = 5;
+        assert_eq!(&vec![1, 5, 3], m.owner());
+    }
+}
@@ -0,0 +1,132 @@
+use core::marker::{PhantomData, PhantomPinned};
+use core::ops::Deref;
+use core::pin::Pin;
+use alloc::boxed::Box;
+
+use crate::pinned::PinnedCache;
+
+/// Like `SelfMonad`, but runs the projection only on first access and returns the cached result
+/// until `clear_cache` is called.
+///
+/// The owner is pinned on construction, so the cached projection never has to follow the owner to a
+/// new address.
+pub struct SelfMonadCached<O, V: ?Sized, F> {
+    owner: O,
+    func: F,
+    value: PinnedCache<V>,
+    phantom: PhantomData<fn() -> V>,
+    _pinned: PhantomPinned
+}
+
+// The cached projection points either into the owner or into memory reachable through it, so it may
+// be sent along with the owner
+unsafe impl<O: Send, V: ?Sized, F: Send> Send for SelfMonadCached<O, V, F> {}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> SelfMonadCached<O, V, F> {
+    pub fn new(owner: O, func: F) -> Pin<Box<Self>> {
+        Box::pin(SelfMonadCached {
+            owner,
+            func,
+            value: PinnedCache::new(),
+            phantom: PhantomData,
+            _pinned: PhantomPinned
+        })
+    }
+
+    /// Returns the projected value, running the projection if there is no cached one.
+    #[inline]
+    pub fn as_ref(self: Pin<&Self>) -> &V {
+        self.get_ref().value()
+    }
+
+    fn value(&self) -> &V {
+        // Every instance lives behind the `Pin` returned by `new`, and `owner_mut` clears the cache
+        // before handing the owner out mutably
+        unsafe { self.value.get_or_init(|| (self.func)(&self.owner)) }
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadCached<O, V, F> {
+    /// Drops the cached projection, so the next access runs the projection again.
+    pub fn clear_cache(self: Pin<&mut Self>) {
+        // The cache is not structurally pinned
+        unsafe { self.get_unchecked_mut() }.value.clear();
+    }
+
+    pub fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    /// Drops the cached projection, since the owner may be changed through the returned reference.
+    pub fn owner_mut(self: Pin<&mut Self>) -> &mut O {
+        // The owner is not structurally pinned, only the cached projection relies on its address and
+        // it is dropped here
+        let this = unsafe { self.get_unchecked_mut() };
+        this.value.clear();
+        &mut this.owner
+    }
+
+    pub fn owner_into(self: Pin<Box<Self>>) -> O {
+        // Moving the owner out drops the cache along with the rest of the monad
+        unsafe { Pin::into_inner_unchecked(self) }.owner
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> Deref for SelfMonadCached<O, V, F> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SelfMonadCached;
+    use std::cell::Cell;
+
+    #[test]
+    fn cached_runs_once() {
+        let calls = Cell::new(0);
+        let m = SelfMonadCached::new(String::from("hello"), |s| {
+            calls.set(calls.get() + 1);
+            &s[0..2]
+        });
+        assert_eq!("he", &**m);
+        assert_eq!("he", &**m);
+        assert_eq!("he", m.as_ref().as_ref());
+        assert_eq!(1, calls.get());
+    }
+
+    #[test]
+    fn cached_clear() {
+        let calls = Cell::new(0);
+        let mut m = SelfMonadCached::new([1, 2, 3, 4], |a| {
+            calls.set(calls.get() + 1);
+            &a[1..3]
+        });
+        assert_eq!([2, 3], **m);
+        m.as_mut().clear_cache();
+        assert_eq!([2, 3], **m);
+        assert_eq!(2, calls.get());
+
+        m.as_mut().owner_mut()[1] = 5;
+        assert_eq!([5, 3], **m);
+        assert_eq!([5, 3], **m);
+        assert_eq!(3, calls.get());
+        assert_eq!([1, 5, 3, 4], m.owner_into());
+    }
+
+    #[test]
+    fn cached_move_box_owner() {
+        let m = SelfMonadCached::new(Box::new([1, 2, 3, 4]), |a| &a[1..3]);
+        assert_eq!([2, 3], **m);
+
+        let mut moved = vec![m];
+        let m = moved.pop().unwrap();
+        assert_eq!([2, 3], **m);
+        let m = Box::new(m);
+        assert_eq!([2, 3], ***m);
+    }
+}
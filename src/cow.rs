@@ -0,0 +1,73 @@
+use core::marker::PhantomData;
+use alloc::borrow::{Cow, ToOwned};
+
+use crate::SelfMonadOwner;
+
+/// Projects the owner with a closure that borrows from it when it can and allocates when it must.
+pub struct SelfMonadCow<O, B: ?Sized, F> {
+    owner: O,
+    func: F,
+    phantom: PhantomData<fn() -> B>
+}
+
+impl<O, B: ?Sized + ToOwned, F: Fn(&O) -> Cow<'_, B>> SelfMonadCow<O, B, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadCow {
+            owner,
+            func,
+            phantom: PhantomData
+        }
+    }
+
+    /// Runs the projection and returns its result.
+    pub fn get(&self) -> Cow<'_, B> {
+        (self.func)(&self.owner)
+    }
+}
+
+impl<O, B: ?Sized, F> SelfMonadOwner<O> for SelfMonadCow<O, B, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadCow, SelfMonadOwner};
+    use std::borrow::Cow;
+
+    fn unescape(s: &str) -> Cow<'_, str> {
+        if s.contains("\\n") {
+            Cow::Owned(s.replace("\\n", "\n"))
+        } else {
+            Cow::Borrowed(s)
+        }
+    }
+
+    #[test]
+    fn cow_borrowed() {
+        let m = SelfMonadCow::new(String::from("hello"), |s| unescape(s));
+        match m.get() {
+            Cow::Borrowed(value) => assert_eq!("hello", value),
+            Cow::Owned(_) => panic!("clean input should be borrowed")
+        }
+    }
+
+    #[test]
+    fn cow_owned() {
+        let mut m = SelfMonadCow::new(String::from("hello"), |s| unescape(s));
+        m.owner_mut().push_str("\\nworld");
+        match m.get() {
+            Cow::Owned(value) => assert_eq!("hello\nworld", value),
+            Cow::Borrowed(_) => panic!("escaped input should be owned")
+        }
+    }
+}
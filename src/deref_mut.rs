@@ -0,0 +1,234 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+use crate::{BorrowDepth, SelfMonad, SelfMonadMut};
+
+/// A shared and a mutable projection of the same value.
+///
+/// `DerefMut` requires `Deref`, and a single closure can't be both `Fn(&O) -> &V` and
+/// `Fn(&mut O) -> &mut V`, so monads that deref mutably carry one closure for each direction.
+pub struct DerefMutFunc<F, G> {
+    func: F,
+    func_mut: G
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V, G: Fn(&mut O) -> &mut V> SelfMonad<O, V, DerefMutFunc<F, G>> {
+    pub fn new_deref_mut(owner: O, func: F, func_mut: G) -> Self {
+        SelfMonad {
+            owner,
+            func: DerefMutFunc { func, func_mut },
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V, G> Deref for SelfMonad<O, V, DerefMutFunc<F, G>> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized, F, G> DerefMut for SelfMonad<O, V, DerefMutFunc<F, G>>
+where
+    F: Fn(&O) -> &V,
+    G: Fn(&mut O) -> &mut V
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        AsMut::as_mut(self)
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V, G> AsRef<V> for SelfMonad<O, V, DerefMutFunc<F, G>> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        (self.func.func)(&self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F, G: Fn(&mut O) -> &mut V> AsMut<V> for SelfMonad<O, V, DerefMutFunc<F, G>> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut V {
+        (self.func.func_mut)(&mut self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V, G> Borrow<V> for SelfMonad<O, V, DerefMutFunc<F, G>> {
+    fn borrow(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F, G> BorrowMut<V> for SelfMonad<O, V, DerefMutFunc<F, G>>
+where
+    F: Fn(&O) -> &V,
+    G: Fn(&mut O) -> &mut V
+{
+    fn borrow_mut(&mut self) -> &mut V {
+        self.as_mut()
+    }
+}
+
+impl<O, V: ?Sized + Index<I>, F: Fn(&O) -> &V, G, I> Index<I> for SelfMonad<O, V, DerefMutFunc<F, G>> {
+    type Output = V::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.as_ref().index(index)
+    }
+}
+
+impl<O, V: ?Sized + IndexMut<I>, F, G, I> IndexMut<I> for SelfMonad<O, V, DerefMutFunc<F, G>>
+where
+    F: Fn(&O) -> &V,
+    G: Fn(&mut O) -> &mut V
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.as_mut().index_mut(index)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+impl<O, V: ?Sized, F, G> SelfMonadMut<O, V, DerefMutFunc<F, G>>
+where
+    F: FnMut(&O) -> &V,
+    G: FnMut(&mut O) -> &mut V
+{
+    pub fn new_deref_mut(owner: O, func: F, func_mut: G) -> Self {
+        SelfMonadMut {
+            owner,
+            func: RefCell::new(DerefMutFunc { func, func_mut }),
+            depth: BorrowDepth::default(),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V, G> Deref for SelfMonadMut<O, V, DerefMutFunc<F, G>> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized, F, G> DerefMut for SelfMonadMut<O, V, DerefMutFunc<F, G>>
+where
+    F: FnMut(&O) -> &V,
+    G: FnMut(&mut O) -> &mut V
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        AsMut::as_mut(self)
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V, G> AsRef<V> for SelfMonadMut<O, V, DerefMutFunc<F, G>> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        let _depth = self.depth.enter();
+        (self.func.borrow_mut().func)(&self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F, G: FnMut(&mut O) -> &mut V> AsMut<V> for SelfMonadMut<O, V, DerefMutFunc<F, G>> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut V {
+        (self.func.get_mut().func_mut)(&mut self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V, G> Borrow<V> for SelfMonadMut<O, V, DerefMutFunc<F, G>> {
+    fn borrow(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F, G> BorrowMut<V> for SelfMonadMut<O, V, DerefMutFunc<F, G>>
+where
+    F: FnMut(&O) -> &V,
+    G: FnMut(&mut O) -> &mut V
+{
+    fn borrow_mut(&mut self) -> &mut V {
+        self.as_mut()
+    }
+}
+
+impl<O, V: ?Sized + Index<I>, F: FnMut(&O) -> &V, G, I> Index<I> for SelfMonadMut<O, V, DerefMutFunc<F, G>> {
+    type Output = V::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.as_ref().index(index)
+    }
+}
+
+impl<O, V: ?Sized + IndexMut<I>, F, G, I> IndexMut<I> for SelfMonadMut<O, V, DerefMutFunc<F, G>>
+where
+    F: FnMut(&O) -> &V,
+    G: FnMut(&mut O) -> &mut V
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.as_mut().index_mut(index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonad, SelfMonadMut, SelfMonadOwner};
+    use std::borrow::{Borrow, BorrowMut};
+
+    #[test]
+    fn deref_mut() {
+        let mut m = SelfMonad::new_deref_mut(vec![4, 3, 2, 1], |v| &v[1..], |v| &mut v[1..]);
+        m[0] = 5;
+        m.sort();
+        assert_eq!([1, 2, 5], *m);
+        assert_eq!(&vec![4, 1, 2, 5], m.owner());
+    }
+
+    #[test]
+    fn mut_deref_mut() {
+        let mut m = SelfMonadMut::new_deref_mut(String::from("hello"), |s| &s[0..2], |s| &mut s[0..2]);
+        m.make_ascii_uppercase();
+        assert_eq!("HE", &*m);
+        assert_eq!("HEllo", m.owner());
+    }
+
+    #[test]
+    fn borrow_mut() {
+        let mut m = SelfMonad::new_deref_mut(vec![1, 2, 3], |v| &v[1..], |v| &mut v[1..]);
+        let value: &mut [i32] = BorrowMut::borrow_mut(&mut m);
+        value[0] = 5;
+        let value: &[i32] = Borrow::borrow(&m);
+        assert_eq!([5, 3], *value);
+
+        let mut m = SelfMonadMut::new_deref_mut(String::from("hello"), |s| &s[1..], |s| &mut s[1..]);
+        let value: &mut str = BorrowMut::borrow_mut(&mut m);
+        value.make_ascii_uppercase();
+        let value: &str = Borrow::borrow(&m);
+        assert_eq!("ELLO", value);
+        assert_eq!("hELLO", m.owner());
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut m = SelfMonad::new_deref_mut(vec![1, 2, 3], |v| &v[1..], |v| &mut v[1..]);
+        m[0] = 5;
+        m[1..].copy_from_slice(&[6]);
+        assert_eq!(5, m[0]);
+        assert_eq!(&vec![1, 5, 6], m.owner());
+    }
+
+    #[test]
+    fn mut_index_mut() {
+        let mut m = SelfMonadMut::new_deref_mut(String::from("hello"), |s| &s[1..], |s| &mut s[1..]);
+        m[0..2].make_ascii_uppercase();
+        assert_eq!("ELlo", &m[..]);
+    }
+}
@@ -0,0 +1,39 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// A projection that panicked while a monad was being built with `try_new`.
+///
+/// The panic is caught with `catch_unwind`, so the panic hook still runs and prints its message, and
+/// a crate built with `panic = "abort"` aborts instead of returning this error. Use `SelfMonadTry` to
+/// report failures without panicking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectionError {
+    message: String
+}
+
+impl ProjectionError {
+    pub(crate) fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => String::from("projection panicked")
+            }
+        };
+        ProjectionError { message }
+    }
+
+    /// The panic message of the projection.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ProjectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "projection failed: {}", self.message)
+    }
+}
+
+impl Error for ProjectionError {}
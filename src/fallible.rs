@@ -0,0 +1,133 @@
+use core::marker::PhantomData;
+
+use crate::SelfMonadOwner;
+
+/// Projects the owner with a closure that may fail.
+pub struct SelfMonadTry<O, V: ?Sized, E, F> {
+    owner: O,
+    func: F,
+    phantom: PhantomData<fn() -> V>,
+    error: PhantomData<fn() -> E>
+}
+
+impl<O, V: ?Sized, E, F: Fn(&O) -> Result<&V, E>> SelfMonadTry<O, V, E, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadTry {
+            owner,
+            func,
+            phantom: PhantomData,
+            error: PhantomData
+        }
+    }
+
+    /// Runs the projection right away, so the monad is only built if the projection succeeds.
+    pub fn try_new(owner: O, func: F) -> Result<Self, E> {
+        func(&owner)?;
+        Ok(SelfMonadTry::new(owner, func))
+    }
+
+    /// Runs the projection, returning its error if it fails.
+    pub fn try_as_ref(&self) -> Result<&V, E> {
+        (self.func)(&self.owner)
+    }
+
+    /// Runs the projection, discarding its error if it fails.
+    pub fn ok(&self) -> Option<&V> {
+        self.try_as_ref().ok()
+    }
+
+    /// Converts the error of the projection with `g`. Nothing is run until `try_as_ref`.
+    pub fn map_err<E2, G>(self, g: G) -> SelfMonadTry<O, V, E2, impl for<'b> Fn(&'b O) -> Result<&'b V, E2>>
+    where
+        G: Fn(E) -> E2
+    {
+        let func = self.func;
+        SelfMonadTry::new(self.owner, move |o: &O| func(o).map_err(&g))
+    }
+
+    /// Falls back to the projection `g` when the projection fails. Nothing is run until `try_as_ref`.
+    pub fn or_else<E2, G>(self, g: G) -> SelfMonadTry<O, V, E2, impl for<'b> Fn(&'b O) -> Result<&'b V, E2>>
+    where
+        G: Fn(&O) -> Result<&V, E2>
+    {
+        let func = self.func;
+        SelfMonadTry::new(self.owner, move |o: &O| func(o).or_else(|_| g(o)))
+    }
+}
+
+impl<O, V: ?Sized, E, F> SelfMonadOwner<O> for SelfMonadTry<O, V, E, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SelfMonadTry;
+    use std::cell::Cell;
+
+    #[test]
+    fn try_ok() {
+        let m = SelfMonadTry::new(String::from("header\nbody"), |s| s.lines().next().ok_or("empty"));
+        assert_eq!(Ok("header"), m.try_as_ref());
+        assert_eq!(Some("header"), m.ok());
+    }
+
+    #[test]
+    fn try_err() {
+        let m = SelfMonadTry::new(String::new(), |s| s.lines().next().ok_or("empty"));
+        assert_eq!(Err("empty"), m.try_as_ref());
+        assert_eq!(None, m.ok());
+    }
+
+    #[test]
+    fn try_closure() {
+        let m = SelfMonadTry::new(vec![1, 2, 3], |v| v.get(1..5).ok_or(v.len()));
+        assert_eq!(Err(3), m.try_as_ref());
+    }
+
+    #[test]
+    fn try_map_err() {
+        let m = SelfMonadTry::new(String::new(), |s| s.lines().next().ok_or("empty"))
+            .map_err(|e| e.len());
+        assert_eq!(Err(5), m.try_as_ref());
+
+        let m = SelfMonadTry::new(String::from("header"), |s| s.lines().next().ok_or("empty"))
+            .map_err(|e| e.len());
+        assert_eq!(Ok("header"), m.try_as_ref());
+    }
+
+    #[test]
+    fn try_or_else() {
+        let calls = Cell::new(0);
+        let m = SelfMonadTry::new(vec![1, 2], |v| v.get(0..3).ok_or("short"))
+            .or_else(|v: &Vec<i32>| {
+                calls.set(calls.get() + 1);
+                v.get(0..0).ok_or(())
+            });
+        assert_eq!(0, calls.get());
+        assert_eq!(Ok(&[][..]), m.try_as_ref());
+        assert_eq!(1, calls.get());
+
+        let m = SelfMonadTry::new(vec![1, 2, 3], |v| v.get(0..3).ok_or("short"))
+            .or_else(|v: &Vec<i32>| v.get(0..0).ok_or(()));
+        assert_eq!(Ok(&[1, 2, 3][..]), m.try_as_ref());
+    }
+
+    #[test]
+    fn try_new() {
+        let m = SelfMonadTry::try_new(String::from("header\nbody"), |s| s.lines().next().ok_or("empty")).unwrap();
+        assert_eq!(Ok("header"), m.try_as_ref());
+
+        let result = SelfMonadTry::try_new(String::new(), |s| s.lines().next().ok_or("empty"));
+        assert_eq!(Some("empty"), result.err());
+    }
+}
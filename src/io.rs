@@ -0,0 +1,85 @@
+use std::fmt;
+use std::io::{self, IoSlice, IoSliceMut, Read, Write};
+
+use crate::SelfMonadMut;
+
+impl<O, V: ?Sized + Read, F: FnMut(&mut O) -> &mut V> Read for SelfMonadMut<O, V, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_mut().read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.as_mut().read_vectored(bufs)
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        self.as_mut().read_to_end(buf)
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        self.as_mut().read_to_string(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.as_mut().read_exact(buf)
+    }
+}
+
+impl<O, V: ?Sized + Write, F: FnMut(&mut O) -> &mut V> Write for SelfMonadMut<O, V, F> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_mut().write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.as_mut().write_vectored(bufs)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.as_mut().flush()
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.as_mut().write_all(buf)
+    }
+
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> io::Result<()> {
+        self.as_mut().write_fmt(fmt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadMut, SelfMonadOwner};
+    use std::io::{Cursor, Read, Write};
+
+    struct Connection {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>
+    }
+
+    #[test]
+    fn read() {
+        let connection = Connection { input: Cursor::new(b"hello".to_vec()), output: Vec::new() };
+        let mut m = SelfMonadMut::new_mut(connection, |c| &mut c.input);
+
+        let mut buf = [0; 2];
+        m.read_exact(&mut buf).unwrap();
+        assert_eq!(b"he", &buf);
+
+        let mut rest = String::new();
+        m.read_to_string(&mut rest).unwrap();
+        assert_eq!("llo", rest);
+        assert_eq!(5, m.owner().input.position());
+    }
+
+    #[test]
+    fn write() {
+        let connection = Connection { input: Cursor::new(Vec::new()), output: Vec::new() };
+        let mut m = SelfMonadMut::new_mut(connection, |c| &mut c.output);
+
+        m.write_all(b"hello").unwrap();
+        write!(m, " {}", 42).unwrap();
+        m.flush().unwrap();
+        assert_eq!(b"hello 42", &m.owner().output[..]);
+    }
+}
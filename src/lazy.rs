@@ -0,0 +1,102 @@
+use core::cell::{Cell, OnceCell};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::SelfMonadOwner;
+
+/// Builds the owner with `init` on first access, then projects it like `SelfMonad`.
+pub struct LazySelfMonad<O, V: ?Sized, Init, F> {
+    owner: OnceCell<O>,
+    init: Cell<Option<Init>>,
+    func: F,
+    phantom: PhantomData<fn() -> V>
+}
+
+impl<O, V: ?Sized, Init: FnOnce() -> O, F: Fn(&O) -> &V> LazySelfMonad<O, V, Init, F> {
+    pub fn new(init: Init, func: F) -> Self {
+        LazySelfMonad {
+            owner: OnceCell::new(),
+            init: Cell::new(Some(init)),
+            func,
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, Init: FnOnce() -> O, F> LazySelfMonad<O, V, Init, F> {
+    /// Returns `true` once the owner has been built.
+    pub fn is_initialized(&self) -> bool {
+        self.owner.get().is_some()
+    }
+
+    fn force(&self) -> &O {
+        self.owner.get_or_init(|| (self.init.take().expect("owner initializer panicked"))())
+    }
+}
+
+impl<O, V: ?Sized, Init: FnOnce() -> O, F: Fn(&O) -> &V> Deref for LazySelfMonad<O, V, Init, F> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized, Init: FnOnce() -> O, F: Fn(&O) -> &V> AsRef<V> for LazySelfMonad<O, V, Init, F> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        (self.func)(self.force())
+    }
+}
+
+/// Every method builds the owner if it has not been built yet.
+impl<O, V: ?Sized, Init: FnOnce() -> O, F> SelfMonadOwner<O> for LazySelfMonad<O, V, Init, F> {
+    fn owner(&self) -> &O {
+        self.force()
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        self.force();
+        self.owner.get_mut().unwrap()
+    }
+
+    fn owner_into(self) -> O {
+        self.force();
+        self.owner.into_inner().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LazySelfMonad, SelfMonadOwner};
+    use std::cell::Cell;
+
+    #[test]
+    fn lazy_builds_once() {
+        let built = Cell::new(0);
+        let m = LazySelfMonad::new(
+            || {
+                built.set(built.get() + 1);
+                String::from("hello")
+            },
+            |s| &s[0..2]
+        );
+        assert!(!m.is_initialized());
+        assert_eq!(0, built.get());
+
+        assert_eq!("he", &*m);
+        assert_eq!("he", m.as_ref());
+        assert_eq!("he", &*m);
+        assert!(m.is_initialized());
+        assert_eq!(1, built.get());
+    }
+
+    #[test]
+    fn lazy_owner() {
+        let mut m = LazySelfMonad::new(|| vec![1, 2, 3], |v| &v[1..]);
+        m.owner_mut().push(4);
+        assert_eq!([2, 3, 4], *m);
+        assert_eq!(vec![1, 2, 3, 4], m.owner_into());
+    }
+}
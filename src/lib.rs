@@ -1,12 +1,113 @@
-use std::cell::{Cell, RefCell};
-use std::ops::Deref;
-use std::marker::PhantomData;
-use std::borrow::Borrow;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cell::{BorrowMutError, Cell, RefCell};
+use core::ops::{Deref, Index};
+use core::slice::SliceIndex;
+use core::marker::PhantomData;
+use core::mem;
+use core::borrow::Borrow;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::cmp::Ordering;
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+
+mod any;
+#[cfg(feature = "alloc")]
+mod arc;
+mod as_ref_owner;
+mod builder;
+#[cfg(feature = "alloc")]
+mod cached;
+#[cfg(feature = "alloc")]
+mod cow;
+mod deref_mut;
+#[cfg(feature = "std")]
+mod error;
+mod fallible;
+#[cfg(feature = "std")]
+mod io;
+mod lazy;
+mod opt;
+mod owned;
+mod owned_cached;
+#[cfg(feature = "alloc")]
+mod pinned;
+pub mod prelude;
+mod projection;
+mod projects_to;
+#[cfg(feature = "alloc")]
+mod rc;
+#[cfg(feature = "serde")]
+mod serialize;
+mod split;
+mod state;
+#[cfg(feature = "std")]
+mod sync;
+mod zip;
+
+pub use any::AnySelfMonad;
+#[cfg(feature = "alloc")]
+pub use arc::SelfMonadArc;
+pub use as_ref_owner::AsRefOwner;
+pub use builder::SelfMonadBuilder;
+#[cfg(feature = "alloc")]
+pub use cached::SelfMonadCached;
+#[cfg(feature = "alloc")]
+pub use cow::SelfMonadCow;
+pub use deref_mut::DerefMutFunc;
+#[cfg(feature = "std")]
+pub use error::ProjectionError;
+pub use fallible::SelfMonadTry;
+pub use lazy::LazySelfMonad;
+pub use opt::{SelfMonadOpt, SelfMonadSafe};
+pub use owned::SelfMonadOwned;
+pub use owned_cached::SelfMonadOwnedCached;
+#[cfg(feature = "alloc")]
+pub use pinned::SelfMonadPinned;
+pub use projection::{Projection, SelfMonadProj};
+pub use projects_to::ProjectsTo;
+#[cfg(feature = "alloc")]
+pub use rc::SelfMonadRc;
+#[cfg(feature = "serde")]
+pub use serialize::{NamedProjection, SelfMonadNamed, SerdeProjection};
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub use serialize::ProjectionRegistry;
+pub use split::SelfMonadSplit;
+pub use state::StatefulFunc;
+#[cfg(feature = "std")]
+pub use sync::SelfMonadMutSync;
+pub use zip::{zip, SelfMonadZip};
+
+/// Returns `true` if `value` lies entirely within the inline storage of `owner`.
+fn is_inline<O, V: ?Sized>(owner: &O, value: &V) -> bool {
+    let start = owner as *const O as usize;
+    let address = value as *const V as *const u8 as usize;
+    address >= start && address + mem::size_of_val(value) <= start + mem::size_of::<O>()
+}
+
+/// Projects the owner with a one-shot closure.
+///
+/// The first `as_ref`, `as_mut` or `deref` runs the closure, every later call panics. To dereference
+/// more than once, `fuse` the monad into a `SelfMonadPinned`, which caches the projected value. The
+/// cache lives there rather than here, since a pointer cached here would dangle once the monad moves.
 pub struct SelfMonadOnce<O, V: ?Sized, F> {
     owner: O,
     func: Cell<Option<F>>,
-    phantom: PhantomData<*const V>
+    phantom: PhantomData<fn() -> V>
 }
 
 impl<O, V: ?Sized, F: FnOnce(&O) -> &V> SelfMonadOnce<O, V, F> {
@@ -17,6 +118,54 @@ impl<O, V: ?Sized, F: FnOnce(&O) -> &V> SelfMonadOnce<O, V, F> {
             phantom: PhantomData
         }
     }
+
+    /// Runs the projection, or returns `None` if it has already run, where `as_ref` would panic.
+    pub fn try_as_ref(&self) -> Option<&V> {
+        self.func.take().map(|func| func(&self.owner))
+    }
+
+    /// Runs the projection without checking whether it has already run, skipping the branch in
+    /// `as_ref` on paths that construct a monad and dereference it right away.
+    ///
+    /// # Safety
+    ///
+    /// The projection must not have run yet. Calling this after `as_ref`, `deref`, `as_mut`,
+    /// `try_as_ref` or a previous `as_ref_unchecked` is undefined behavior.
+    pub unsafe fn as_ref_unchecked(&self) -> &V {
+        (self.func.take().unwrap_unchecked())(&self.owner)
+    }
+
+    /// Runs the projection and returns the projected value. The same as `as_ref`.
+    pub fn value(&self) -> &V {
+        self.as_ref()
+    }
+
+    /// Passes the projected value to `g` and returns its result.
+    pub fn with_value<R, G: FnOnce(&V) -> R>(&self, g: G) -> R {
+        g(self.as_ref())
+    }
+
+    /// Runs the projection and clones the projected value out.
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_value(&self) -> V::Owned where V: ToOwned {
+        self.as_ref().to_owned()
+    }
+
+    /// Runs the projection and returns the address of the projected value, for identity checks.
+    pub fn value_ptr(&self) -> *const V {
+        self.as_ref()
+    }
+
+    /// Moves the owner and the projection into a `SelfMonadPinned`, which runs the projection once
+    /// and returns the cached value on every later access. `None` if the projection has already run.
+    ///
+    /// The pinned monad keeps the owner in place and never hands the projection out again, so the
+    /// cached value can't outlive or lose track of its owner.
+    #[cfg(feature = "alloc")]
+    pub fn fuse(self) -> Option<Pin<Box<SelfMonadPinned<O, V, F>>>> {
+        let (owner, func) = self.into_parts();
+        func.map(|func| SelfMonadPinned::new(owner, func))
+    }
 }
 
 impl<O, V: ?Sized, F: FnOnce(&mut O) -> &mut V> SelfMonadOnce<O, V, F> {
@@ -27,34 +176,155 @@ impl<O, V: ?Sized, F: FnOnce(&mut O) -> &mut V> SelfMonadOnce<O, V, F> {
             phantom: PhantomData
         }
     }
+
+    /// Runs the projection and returns the projected value mutably. The same as `as_mut`.
+    pub fn value_mut(&mut self) -> &mut V {
+        self.as_mut()
+    }
+
+    /// Runs the projection mutably, or returns `None` if it has already run, where `as_mut` would
+    /// panic.
+    pub fn try_as_mut(&mut self) -> Option<&mut V> {
+        let owner = &mut self.owner;
+        self.func.get_mut().take().map(move |func| func(owner))
+    }
+
+    /// Passes the projected value mutably to `g` and returns its result.
+    pub fn with_value_mut<R, G: FnOnce(&mut V) -> R>(&mut self, g: G) -> R {
+        g(self.as_mut())
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOnce<O, V, F> {
+    /// Replaces the projection, keeping the owner in place.
+    pub fn with_func<V2: ?Sized, G: FnOnce(&O) -> &V2>(self, g: G) -> SelfMonadOnce<O, V2, G> {
+        SelfMonadOnce::new(self.owner_into(), g)
+    }
+
+    /// Returns the address of the owner, for identity checks.
+    pub fn owner_ptr(&self) -> *const O {
+        &self.owner
+    }
+
+    /// Splits the monad into its owner and its projection, `None` if the projection has already run.
+    pub fn into_parts(self) -> (O, Option<F>) {
+        (self.owner, self.func.into_inner())
+    }
+
+    /// Returns `true` once the projection has been run by `as_ref`, `as_mut` or `deref`.
+    pub fn is_consumed(&self) -> bool {
+        let func = self.func.take();
+        let consumed = func.is_none();
+        self.func.set(func);
+        consumed
+    }
+}
+
+/// A `SelfMonadOnce` with a boxed projection, so monads with different closures share one type.
+#[cfg(feature = "alloc")]
+pub type BoxedSelfMonadOnce<O, V> = SelfMonadOnce<O, V, Box<dyn FnOnce(&O) -> &V>>;
+
+#[cfg(feature = "alloc")]
+impl<O, V: ?Sized> BoxedSelfMonadOnce<O, V> {
+    /// Boxes the projection into a `BoxedSelfMonadOnce`.
+    pub fn box_projection<F: FnOnce(&O) -> &V + 'static>(owner: O, func: F) -> Self {
+        SelfMonadOnce::new(owner, Box::new(func))
+    }
 }
 
 impl<O, V: ?Sized, F: FnOnce(&O) -> &V> Deref for SelfMonadOnce<O, V, F> {
     type Target = V;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
         AsRef::as_ref(self)
     }
 }
 
 impl<O, V: ?Sized, F: FnOnce(&O) -> &V> AsRef<V> for SelfMonadOnce<O, V, F> {
+    #[inline]
     fn as_ref(&self) -> &V {
-        (self.func.take().unwrap())(&self.owner)
+        self.try_as_ref().expect("projection has already run")
     }
 }
 
 impl<O, V: ?Sized, F: FnOnce(&mut O) -> &mut V> AsMut<V> for SelfMonadOnce<O, V, F> {
+    #[inline]
     fn as_mut(&mut self) -> &mut V {
-        (self.func.take().unwrap())(&mut self.owner)
+        self.try_as_mut().expect("projection has already run")
+    }
+}
+
+/// Shows whether the projection has run, without running it.
+impl<O, V: ?Sized, F> Debug for SelfMonadOnce<O, V, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let state = if self.is_consumed() { "<consumed>" } else { "<unconsumed>" };
+        f.debug_tuple("SelfMonadOnce").field(&format_args!("{}", state)).finish()
+    }
+}
+
+impl<O: Default, V: ?Sized, F: Default> Default for SelfMonadOnce<O, V, F> {
+    fn default() -> Self {
+        SelfMonadOnce {
+            owner: O::default(),
+            func: Cell::new(Some(F::default())),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, F: FnOnce(&O) -> &V> From<(O, F)> for SelfMonadOnce<O, V, F> {
+    fn from((owner, func): (O, F)) -> Self {
+        SelfMonadOnce::new(owner, func)
+    }
+}
+
+/// Borrows the projected value. Like `as_ref`, this runs the one-shot projection if it has not run yet.
+impl<O, V: ?Sized, F: FnOnce(&O) -> &V> Borrow<V> for SelfMonadOnce<O, V, F> {
+    fn borrow(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+/// Formats the projected value. Like `as_ref`, this runs the one-shot projection if it has not run yet.
+impl<O, V: ?Sized + Display, F: FnOnce(&O) -> &V> Display for SelfMonadOnce<O, V, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_ref(), f)
     }
 }
 
-///-------------------------------------------------------------------------------------------------
+//--------------------------------------------------------------------------------------------------
 
+/// Projects the owner with a repeatable closure.
+///
+/// The monad is `Send` whenever its owner and its projection are, so it can move to another thread:
+///
+/// ```
+/// use std::thread;
+/// use self_monad::SelfMonad;
+///
+/// fn assert_send<T: Send>(_: &T) {}
+///
+/// let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+/// assert_send(&m);
+/// assert_eq!("he", thread::spawn(move || m.to_string()).join().unwrap());
+/// ```
+///
+/// A projection capturing something that isn't `Send`, like an `Rc`, keeps the monad on its thread:
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use std::thread;
+/// use self_monad::SelfMonad;
+///
+/// let start = Rc::new(1);
+/// let m = SelfMonad::new(vec![1, 2, 3], move |v| &v[*start..]);
+/// thread::spawn(move || m.len());
+/// ```
 pub struct SelfMonad<O, V: ?Sized, F> {
     owner: O,
     func: F,
-    phantom: PhantomData<*const V>
+    phantom: PhantomData<fn() -> V>
 }
 
 impl<O, V: ?Sized, F: Fn(&O) -> &V> SelfMonad<O, V, F> {
@@ -65,9 +335,179 @@ impl<O, V: ?Sized, F: Fn(&O) -> &V> SelfMonad<O, V, F> {
             phantom: PhantomData
         }
     }
+
+    /// Builds a monad borrowing `owner` instead of taking it, for owners shared elsewhere.
+    pub fn from_ref<'a>(owner: &'a O, func: F) -> SelfMonad<&'a O, V, impl for<'b> Fn(&'b &'a O) -> &'b V> {
+        SelfMonad::new(owner, move |o: &&'a O| func(o))
+    }
+
+    /// Like `new`, but debug builds run the projection once and assert that the projected value lies
+    /// within the inline storage of the owner, e.g. a field or an element of an array.
+    ///
+    /// Projections into memory the owner only points to, like the heap buffer of a `String`, fail
+    /// the check, so use `new` for them.
+    pub fn new_checked(owner: O, func: F) -> Self {
+        debug_assert!(is_inline(&owner, func(&owner)), "projection does not point into the owner");
+        SelfMonad::new(owner, func)
+    }
+
+    /// Runs the projection once right away, so a panicking projection, like an out of bounds slice,
+    /// is caught on construction instead of on first access. See `ProjectionError` for its limits.
+    #[cfg(feature = "std")]
+    pub fn try_new(owner: O, func: F) -> Result<Self, ProjectionError> {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            func(&owner);
+        }))
+        .map_err(ProjectionError::from_panic)?;
+        Ok(SelfMonad::new(owner, func))
+    }
+
+    /// Projects the projected value further, keeping the same owner.
+    ///
+    /// `V: 'static` is needed since the composed closure has to work for every borrow of the owner,
+    /// and the `V: 'a` that `F` implies for each borrow can't be carried into a new closure. Owned
+    /// projections like `str` or `[u8]` meet it.
+    pub fn map<V2: ?Sized, G: Fn(&V) -> &V2>(self, g: G) -> SelfMonad<O, V2, impl Fn(&O) -> &V2>
+    where
+        V: 'static
+    {
+        let func = self.func;
+        SelfMonad::new(self.owner, move |o| g(func(o)))
+    }
+
+    /// Chains a projection that borrows from the projected value. The same as `map`, including the
+    /// `V: 'static` bound.
+    pub fn and_then<V2: ?Sized, G: Fn(&V) -> &V2>(self, g: G) -> SelfMonad<O, V2, impl Fn(&O) -> &V2>
+    where
+        V: 'static
+    {
+        self.map(g)
+    }
+
+    /// Converts the projected value with a fallible `g`, e.g. `str::from_utf8` on a byte projection.
+    /// The conversion is attempted on every access to the returned `SelfMonadTry`. Needs `V: 'static`
+    /// for the same reason as `map`.
+    pub fn try_map<V2, E, G>(self, g: G) -> SelfMonadTry<O, V2, E, impl for<'b> Fn(&'b O) -> Result<&'b V2, E>>
+    where
+        V: 'static,
+        V2: ?Sized,
+        G: Fn(&V) -> Result<&V2, E>
+    {
+        let func = self.func;
+        SelfMonadTry::new(self.owner, move |o: &O| g(func(o)))
+    }
+
+    /// Runs the projection and returns the projected value. The same as `as_ref`.
+    pub fn value(&self) -> &V {
+        self.as_ref()
+    }
+
+    /// Passes the projected value to `g` and returns its result.
+    pub fn with_value<R, G: FnOnce(&V) -> R>(&self, g: G) -> R {
+        g(self.as_ref())
+    }
+
+    /// Returns the owner together with the projected value.
+    pub fn owner_and_value(&self) -> (&O, &V) {
+        (&self.owner, self.as_ref())
+    }
+
+    /// Runs the projection and clones the projected value out.
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_value(&self) -> V::Owned where V: ToOwned {
+        self.as_ref().to_owned()
+    }
+
+    /// Runs the projection and returns the address of the projected value, for identity checks.
+    pub fn value_ptr(&self) -> *const V {
+        self.as_ref()
+    }
+
+    /// Transforms the owner with `g`, projecting the new owner through its `AsRef<O>` view of the old one.
+    pub fn map_owner<O2: AsRef<O>, G: FnOnce(O) -> O2>(self, g: G) -> SelfMonad<O2, V, impl Fn(&O2) -> &V>
+    where
+        O: 'static
+    {
+        let func = self.func;
+        SelfMonad::new(g(self.owner), move |o| func(o.as_ref()))
+    }
+
+    /// Boxes the monad behind `Deref`, forgetting its owner and projection types.
+    #[cfg(feature = "alloc")]
+    pub fn into_deref_box(self) -> Box<dyn Deref<Target = V>> where O: 'static, V: 'static, F: 'static {
+        Box::new(self)
+    }
+
+    /// Keeps the projected value only when `p` accepts it. Nothing is run until `get`.
+    pub fn filter<P>(self, p: P) -> SelfMonadOpt<O, V, impl for<'b> Fn(&'b O) -> Option<&'b V>>
+    where
+        P: Fn(&V) -> bool
+    {
+        let func = self.func;
+        SelfMonadOpt::new(self.owner, move |o: &O| Some(func(o)).filter(|v| p(v)))
+    }
+
+    /// Calls `g` with the projected value and returns the monad unchanged.
+    pub fn inspect<G: FnOnce(&V)>(self, g: G) -> Self {
+        g(self.as_ref());
+        self
+    }
+
+    /// Returns a monad that borrows the projected value as its owner and projects it further, leaving
+    /// this monad untouched.
+    pub fn reborrow<'a, V2, G>(&'a self, g: G) -> SelfMonad<&'a V, V2, impl for<'b> Fn(&'b &'a V) -> &'b V2>
+    where
+        V2: ?Sized,
+        G: Fn(&V) -> &V2
+    {
+        SelfMonad::new(self.as_ref(), move |v: &&'a V| g(v))
+    }
+
+    /// Starts a new monad owning the borrowed projected value. The same as `reborrow`.
+    pub fn then_project<'a, V2, G>(&'a self, g: G) -> SelfMonad<&'a V, V2, impl for<'b> Fn(&'b &'a V) -> &'b V2>
+    where
+        V2: ?Sized,
+        G: Fn(&V) -> &V2
+    {
+        self.reborrow(g)
+    }
+
+    /// Views the projected value as a `str`, for APIs taking `impl AsRef<str>`.
+    ///
+    /// A blanket `AsRef<U>` forwarding to `V` would overlap with `AsRef<V>`, hence the helper.
+    pub fn as_str(&self) -> &str where V: AsRef<str> {
+        self.as_ref().as_ref()
+    }
+
+    /// Views the projected value as bytes, for APIs taking `impl AsRef<[u8]>`.
+    pub fn as_bytes(&self) -> &[u8] where V: AsRef<[u8]> {
+        self.as_ref().as_ref()
+    }
+
+    /// Views the projected value as a trait object, e.g. `m.as_dyn::<dyn Display>(|v| v)`.
+    ///
+    /// Stable Rust can't bound on unsizing, so the coercion is spelled out by `coerce`.
+    pub fn as_dyn<U: ?Sized>(&self, coerce: fn(&V) -> &U) -> &U {
+        coerce(self.as_ref())
+    }
+}
+
+impl<O, O2, V: ?Sized, F, F2> SelfMonad<O, SelfMonad<O2, V, F2>, F>
+where
+    F: Fn(&O) -> &SelfMonad<O2, V, F2>,
+    F2: Fn(&O2) -> &V
+{
+    /// Collapses a monad projecting into another monad, composing the outer projection with the inner
+    /// one. The inner monad is reached through the outer projection, so it stays owned by `O`.
+    pub fn flatten(self) -> SelfMonad<O, V, impl Fn(&O) -> &V>
+    where
+        SelfMonad<O2, V, F2>: 'static
+    {
+        self.map(|inner| inner.value())
+    }
 }
 
-impl<'a, O: 'a, V: ?Sized, F: Fn(&mut O) -> &mut V> SelfMonad<O, V, F> {
+impl<O, V: ?Sized, F: Fn(&mut O) -> &mut V> SelfMonad<O, V, F> {
     pub fn new_mut(owner: O, func: F) -> Self {
         SelfMonad {
             owner,
@@ -75,218 +515,1929 @@ impl<'a, O: 'a, V: ?Sized, F: Fn(&mut O) -> &mut V> SelfMonad<O, V, F> {
             phantom: PhantomData
         }
     }
-}
 
-impl<O, V: ?Sized, F: Fn(&O) -> &V> Deref for SelfMonad<O, V, F> {
-    type Target = V;
+    /// Runs the projection and returns the projected value mutably. The same as `as_mut`.
+    pub fn value_mut(&mut self) -> &mut V {
+        self.as_mut()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        AsRef::as_ref(self)
+    /// Passes the projected value mutably to `g` and returns its result.
+    pub fn with_value_mut<R, G: FnOnce(&mut V) -> R>(&mut self, g: G) -> R {
+        g(self.as_mut())
     }
-}
 
-impl<O, V: ?Sized, F: Fn(&O) -> &V> AsRef<V> for SelfMonad<O, V, F> {
-    fn as_ref(&self) -> &V {
-        (self.func)(&self.owner)
+    /// Puts `new` in place of the projected value and returns the old one.
+    pub fn swap_value(&mut self, new: V) -> V where V: Sized {
+        mem::replace(self.as_mut(), new)
     }
 }
 
-impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> AsMut<V> for SelfMonad<O, V, F> {
-    fn as_mut(&mut self) -> &mut V {
-        (self.func)(&mut self.owner)
+impl<O, V: ?Sized, F> SelfMonad<O, V, F> {
+    /// Replaces the projection, keeping the owner in place.
+    pub fn with_func<V2: ?Sized, G: Fn(&O) -> &V2>(self, g: G) -> SelfMonad<O, V2, G> {
+        SelfMonad::new(self.owner_into(), g)
     }
-}
 
-///-------------------------------------------------------------------------------------------------
+    /// Returns the address of the owner, for identity checks.
+    pub fn owner_ptr(&self) -> *const O {
+        &self.owner
+    }
 
-pub struct SelfMonadMut<O, V: ?Sized, F> {
-    owner: O,
-    func: RefCell<F>,
-    phantom: PhantomData<*const V>
-}
+    /// Splits the monad into its owner and its projection.
+    pub fn into_parts(self) -> (O, F) {
+        (self.owner, self.func)
+    }
 
-impl<O, V: ?Sized, F: FnMut(&O) -> &V> SelfMonadMut<O, V, F> {
-    pub fn new(owner: O, func: F) -> Self {
+    /// Clones the projection so it can be reused over another owner.
+    /// Works with function pointers and any `Clone` closure.
+    pub fn clone_projection(&self) -> F where F: Clone {
+        self.func.clone()
+    }
+
+    /// Moves the owner and the projection into a `SelfMonadMut`.
+    pub fn into_mut(self) -> SelfMonadMut<O, V, F> {
         SelfMonadMut {
-            owner,
-            func: RefCell::new(func),
+            owner: self.owner,
+            func: RefCell::new(self.func),
+            depth: BorrowDepth::default(),
             phantom: PhantomData
         }
     }
 }
 
-impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> SelfMonadMut<O, V, F> {
-    pub fn new_mut(owner: O, func: F) -> Self {
-        SelfMonadMut {
+impl<O, V: ?Sized> SelfMonad<O, V, fn(&O) -> &V> {
+    /// Builds a monad with a function pointer projection in a `const` context.
+    pub const fn new_const(owner: O, func: fn(&O) -> &V) -> Self {
+        SelfMonad {
             owner,
-            func: RefCell::new(func),
+            func,
             phantom: PhantomData
         }
     }
 }
 
-impl<O, V: ?Sized, F: FnMut(&O) -> &V> Deref for SelfMonadMut<O, V, F> {
+/// A `SelfMonad` with a boxed projection, so monads with different closures share one type.
+#[cfg(feature = "alloc")]
+pub type BoxedSelfMonad<O, V> = SelfMonad<O, V, Box<dyn Fn(&O) -> &V>>;
+
+#[cfg(feature = "alloc")]
+impl<O, V: ?Sized> BoxedSelfMonad<O, V> {
+    /// Boxes the projection into a `BoxedSelfMonad`.
+    pub fn box_projection<F: Fn(&O) -> &V + 'static>(owner: O, func: F) -> Self {
+        SelfMonad::new(owner, Box::new(func))
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> Deref for SelfMonad<O, V, F> {
     type Target = V;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
         AsRef::as_ref(self)
     }
 }
 
-impl<O, V: ?Sized, F: FnMut(&O) -> &V> AsRef<V> for SelfMonadMut<O, V, F> {
+impl<O, V: ?Sized, F: Fn(&O) -> &V> AsRef<V> for SelfMonad<O, V, F> {
+    #[inline]
     fn as_ref(&self) -> &V {
-        (&mut *self.func.borrow_mut())(&self.owner)
+        (self.func)(&self.owner)
     }
 }
 
-impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> AsMut<V> for SelfMonadMut<O, V, F> {
+impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> AsMut<V> for SelfMonad<O, V, F> {
+    #[inline]
     fn as_mut(&mut self) -> &mut V {
-        (&mut *self.func.borrow_mut())(&mut self.owner)
+        (self.func)(&mut self.owner)
     }
 }
 
-///-------------------------------------------------------------------------------------------------
-
-pub trait SelfMonadOwner<O> {
-    fn owner(&self) -> &O;
-    fn owner_mut(&mut self) -> &mut O;
-    fn owner_into(self) -> O;
+impl<O: Clone, V: ?Sized, F: Clone> Clone for SelfMonad<O, V, F> {
+    fn clone(&self) -> Self {
+        SelfMonad {
+            owner: self.owner.clone(),
+            func: self.func.clone(),
+            phantom: PhantomData
+        }
+    }
 }
 
-impl<O, V, F> SelfMonadOwner<O> for SelfMonadOnce<O, V, F> {
-    fn owner(&self) -> &O {
-        &self.owner
+impl<O: Default, V: ?Sized, F: Default> Default for SelfMonad<O, V, F> {
+    fn default() -> Self {
+        SelfMonad {
+            owner: O::default(),
+            func: F::default(),
+            phantom: PhantomData
+        }
     }
+}
 
-    fn owner_mut(&mut self) -> &mut O {
-        &mut self.owner
-    }
+impl<O, V: ?Sized + Index<I>, F: Fn(&O) -> &V, I> Index<I> for SelfMonad<O, V, F> {
+    type Output = V::Output;
 
-    fn owner_into(self) -> O {
-        self.owner
+    fn index(&self, index: I) -> &Self::Output {
+        self.as_ref().index(index)
     }
 }
 
-impl<O, V, F> SelfMonadOwner<O> for SelfMonad<O, V, F> {
-    fn owner(&self) -> &O {
-        &self.owner
+impl<O, V: ?Sized, F: Fn(&O) -> &V> From<(O, F)> for SelfMonad<O, V, F> {
+    fn from((owner, func): (O, F)) -> Self {
+        SelfMonad::new(owner, func)
     }
+}
 
-    fn owner_mut(&mut self) -> &mut O {
-        &mut self.owner
-    }
+impl<'a, O, V: ?Sized, F: Fn(&O) -> &V> IntoIterator for &'a SelfMonad<O, V, F> where &'a V: IntoIterator {
+    type Item = <&'a V as IntoIterator>::Item;
+    type IntoIter = <&'a V as IntoIterator>::IntoIter;
 
-    fn owner_into(self) -> O {
-        self.owner
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().into_iter()
     }
 }
 
-impl<O, V, F> SelfMonadOwner<O> for SelfMonadMut<O, V, F> {
-    fn owner(&self) -> &O {
-        &self.owner
+impl<O, V: ?Sized, F: Fn(&O) -> &V> Borrow<V> for SelfMonad<O, V, F> {
+    fn borrow(&self) -> &V {
+        self.as_ref()
     }
+}
 
-    fn owner_mut(&mut self) -> &mut O {
-        &mut self.owner
+impl<O, V: ?Sized + Debug, F: Fn(&O) -> &V> Debug for SelfMonad<O, V, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SelfMonad").field(&self.as_ref()).finish()
     }
+}
 
-    fn owner_into(self) -> O {
-        self.owner
+impl<O, V: ?Sized + Display, F: Fn(&O) -> &V> Display for SelfMonad<O, V, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_ref(), f)
     }
 }
 
-/// Tests ------------------------------------------------------------------------------------------
+impl<O, V, F, O2, V2, F2> PartialEq<SelfMonad<O2, V2, F2>> for SelfMonad<O, V, F>
+where
+    V: ?Sized + PartialEq<V2>,
+    V2: ?Sized,
+    F: Fn(&O) -> &V,
+    F2: Fn(&O2) -> &V2
+{
+    fn eq(&self, other: &SelfMonad<O2, V2, F2>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
 
-#[cfg(test)]
-mod test_once {
-    use crate::SelfMonadOnce;
-    use std::borrow::BorrowMut;
-    use std::ops::Deref;
+impl<O, V: ?Sized + Eq, F: Fn(&O) -> &V> Eq for SelfMonad<O, V, F> {}
 
-    #[test]
-    fn once_pointer() {
-        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
-        assert_eq!("he", &*m);
+impl<O, V: ?Sized + Hash, F: Fn(&O) -> &V> Hash for SelfMonad<O, V, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
     }
+}
 
-    #[test]
-    fn once_closure() {
-        let c: Box<dyn FnOnce(&String) -> &str> = Box::new(|s| &s[0..2]);
-        let m = SelfMonadOnce::new(String::from("hello"), c);
-        assert_eq!("he", m.deref());
+impl<O, V, F, O2, V2, F2> PartialOrd<SelfMonad<O2, V2, F2>> for SelfMonad<O, V, F>
+where
+    V: ?Sized + PartialOrd<V2>,
+    V2: ?Sized,
+    F: Fn(&O) -> &V,
+    F2: Fn(&O2) -> &V2
+{
+    fn partial_cmp(&self, other: &SelfMonad<O2, V2, F2>) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
     }
+}
 
-    #[test]
-    fn once_as_ref() {
-        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
-        assert_eq!("he", m.as_ref());
+impl<O, V: ?Sized + Ord, F: Fn(&O) -> &V> Ord for SelfMonad<O, V, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
     }
+}
 
-    #[test]
-    fn once_closure_as_mut() {
-        let c: Box<dyn FnOnce(&mut String) -> &mut str> = Box::new(|s| s[0..2].borrow_mut());
-        let mut m = SelfMonadOnce::new_mut(String::from("hello"), c);
-        assert_eq!("he", m.as_mut());
+impl<O, F: Fn(&O) -> &str> PartialEq<str> for SelfMonad<O, str, F> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
     }
 }
 
-#[cfg(test)]
-mod test {
-    use crate::SelfMonad;
-    use std::borrow::BorrowMut;
+impl<'a, O, F: Fn(&O) -> &str> PartialEq<&'a str> for SelfMonad<O, str, F> {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_ref() == *other
+    }
+}
 
-    #[test]
-    fn pointer_twice() {
-        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
-        assert_eq!("he", &*m);
-        assert_eq!("he", &*m);
+#[cfg(feature = "alloc")]
+impl<O, F: Fn(&O) -> &str> PartialEq<String> for SelfMonad<O, str, F> {
+    fn eq(&self, other: &String) -> bool {
+        self.as_ref() == other
     }
+}
 
-    #[test]
-    fn closure() {
-        let c: Box<dyn Fn(&String) -> &str> = Box::new(|s| &s[0..2]);
-        let m = SelfMonad::new(String::from("hello"), c);
-        assert_eq!("he", &*m);
+impl<O, F: Fn(&O) -> &str> PartialEq<SelfMonad<O, str, F>> for str {
+    fn eq(&self, other: &SelfMonad<O, str, F>) -> bool {
+        self == other.as_ref()
     }
+}
 
-    #[test]
-    fn closure_as_mut() {
-        let c: Box<dyn Fn(&mut String) -> &mut str> = Box::new(|s| s[0..2].borrow_mut());
-        let mut m = SelfMonad::new_mut(String::from("hello"), c);
-        assert_eq!("he", m.as_mut());
+impl<O, T, U, F: Fn(&O) -> &[T]> PartialEq<[U]> for SelfMonad<O, [T], F> where T: PartialEq<U> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_ref() == other
     }
+}
 
-    #[test]
-    fn as_ref() {
-        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
-        assert_eq!("he", m.as_ref());
+impl<O, T, U, F: Fn(&O) -> &[U]> PartialEq<SelfMonad<O, [U], F>> for [T] where T: PartialEq<U> {
+    fn eq(&self, other: &SelfMonad<O, [U], F>) -> bool {
+        self == other.as_ref()
     }
 }
 
-#[cfg(test)]
-mod test_mut {
-    use crate::SelfMonadMut;
-    use std::borrow::BorrowMut;
+impl<O, F: Fn(&O) -> &str> PartialOrd<str> for SelfMonad<O, str, F> {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other)
+    }
+}
 
-    #[test]
-    fn mut_pointer_twice() {
-        let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
-        assert_eq!("he", &*m);
-        assert_eq!("he", &*m);
+impl<'a, O, F: Fn(&O) -> &str> PartialOrd<&'a str> for SelfMonad<O, str, F> {
+    fn partial_cmp(&self, other: &&'a str) -> Option<Ordering> {
+        self.as_ref().partial_cmp(*other)
     }
+}
 
-    #[test]
-    fn mut_closure() {
-        let c: Box<dyn FnMut(&String) -> &str> = Box::new(|s| &s[0..2]);
-        let m = SelfMonadMut::new(String::from("hello"), c);
-        assert_eq!("he", &*m);
+impl<O, F: Fn(&O) -> &str> PartialOrd<SelfMonad<O, str, F>> for str {
+    fn partial_cmp(&self, other: &SelfMonad<O, str, F>) -> Option<Ordering> {
+        self.partial_cmp(other.as_ref())
     }
+}
 
-    #[test]
-    fn mut_closure_as_mut() {
-        let c: Box<dyn FnMut(&mut String) -> &mut str> = Box::new(|s| s[0..2].borrow_mut());
-        let mut m = SelfMonadMut::new_mut(String::from("hello"), c);
-        assert_eq!("he", m.as_mut());
+impl<O, T: PartialOrd, F: Fn(&O) -> &[T]> PartialOrd<[T]> for SelfMonad<O, [T], F> {
+    fn partial_cmp(&self, other: &[T]) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other)
+    }
+}
+
+impl<O, T: PartialOrd, F: Fn(&O) -> &[T]> PartialOrd<SelfMonad<O, [T], F>> for [T] {
+    fn partial_cmp(&self, other: &SelfMonad<O, [T], F>) -> Option<Ordering> {
+        self.partial_cmp(other.as_ref())
+    }
+}
+
+/// Projects the `range` of the elements of an array, or of any owner viewable as a slice, e.g.
+/// `from_array([1, 2, 3, 4], 1..3)`.
+pub fn from_array<A, T, R>(array: A, range: R) -> SelfMonad<A, [T], impl Fn(&A) -> &[T]>
+where
+    A: AsRef<[T]>,
+    R: SliceIndex<[T], Output = [T]> + Clone
+{
+    SelfMonad::new(array, move |a: &A| &a.as_ref()[range.clone()])
+}
+
+/// Lazily turns every owner of `iter` into a `SelfMonad` with a clone of `func`.
+pub fn monads_from_iter<I, O, V, F>(iter: I, func: F) -> impl Iterator<Item = SelfMonad<O, V, F>>
+where
+    I: IntoIterator<Item = O>,
+    V: ?Sized,
+    F: Fn(&O) -> &V + Clone
+{
+    iter.into_iter().map(move |owner| SelfMonad::new(owner, func.clone()))
+}
+
+//--------------------------------------------------------------------------------------------------
+
+pub struct SelfMonadMut<O, V: ?Sized, F> {
+    owner: O,
+    func: RefCell<F>,
+    depth: BorrowDepth,
+    phantom: PhantomData<fn() -> V>
+}
+
+/// Counts the projections of a `SelfMonadMut` running at once. Only debug builds keep the count.
+#[derive(Default)]
+struct BorrowDepth {
+    #[cfg(debug_assertions)]
+    count: Cell<usize>
+}
+
+impl BorrowDepth {
+    fn get(&self) -> usize {
+        #[cfg(debug_assertions)]
+        return self.count.get();
+        #[cfg(not(debug_assertions))]
+        0
+    }
+
+    fn enter(&self) -> DepthGuard<'_> {
+        #[cfg(debug_assertions)]
+        self.count.set(self.count.get() + 1);
+        DepthGuard(self)
+    }
+}
+
+// Leaves the projection on drop, so a panicking projection doesn't leave the count raised
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+struct DepthGuard<'a>(&'a BorrowDepth);
+
+impl Drop for DepthGuard<'_> {
+    fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        self.0.count.set(self.0.count.get() - 1);
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> SelfMonadMut<O, V, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadMut {
+            owner,
+            func: RefCell::new(func),
+            depth: BorrowDepth::default(),
+            phantom: PhantomData
+        }
+    }
+
+    /// Runs the projection and returns the projected value. The same as `as_ref`.
+    pub fn value(&self) -> &V {
+        self.as_ref()
+    }
+
+    /// Passes the projected value to `g` and returns its result.
+    pub fn with_value<R, G: FnOnce(&V) -> R>(&self, g: G) -> R {
+        g(self.as_ref())
+    }
+
+    /// Returns the owner together with the projected value.
+    pub fn owner_and_value(&self) -> (&O, &V) {
+        (&self.owner, self.as_ref())
+    }
+
+    /// Runs the projection and clones the projected value out.
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_value(&self) -> V::Owned where V: ToOwned {
+        self.as_ref().to_owned()
+    }
+
+    /// Runs the projection and returns the address of the projected value, for identity checks.
+    pub fn value_ptr(&self) -> *const V {
+        self.as_ref()
+    }
+
+    /// Runs the projection, returning an error instead of panicking if it is already running, as
+    /// when `as_ref` is re-entered from inside the projection.
+    pub fn try_as_ref(&self) -> Result<&V, BorrowMutError> {
+        let _depth = self.depth.enter();
+        Ok((*self.func.try_borrow_mut()?)(&self.owner))
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> SelfMonadMut<O, V, F> {
+    pub fn new_mut(owner: O, func: F) -> Self {
+        SelfMonadMut {
+            owner,
+            func: RefCell::new(func),
+            depth: BorrowDepth::default(),
+            phantom: PhantomData
+        }
+    }
+
+    /// Runs the projection and returns the projected value mutably. The same as `as_mut`.
+    pub fn value_mut(&mut self) -> &mut V {
+        self.as_mut()
+    }
+
+    /// Passes the projected value mutably to `g` and returns its result.
+    pub fn with_value_mut<R, G: FnOnce(&mut V) -> R>(&mut self, g: G) -> R {
+        g(self.as_mut())
+    }
+
+    /// Puts `new` in place of the projected value and returns the old one.
+    pub fn swap_value(&mut self, new: V) -> V where V: Sized {
+        mem::replace(self.as_mut(), new)
+    }
+
+    /// Projects the mutably projected value further, keeping the same owner. Needs `V: 'static` for
+    /// the same reason as `SelfMonad::map`.
+    pub fn map_mut<V2, G>(self, mut g: G) -> SelfMonadMut<O, V2, impl for<'b> FnMut(&'b mut O) -> &'b mut V2>
+    where
+        V: 'static,
+        V2: ?Sized,
+        G: FnMut(&mut V) -> &mut V2
+    {
+        let mut func = self.func.into_inner();
+        SelfMonadMut::new_mut(self.owner, move |o: &mut O| g(func(o)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T, F: FnMut(&mut O) -> &mut Vec<T>> SelfMonadMut<O, Vec<T>, F> {
+    /// Keeps only the elements of the projected vector for which `p` returns `true`.
+    pub fn retain<P: FnMut(&T) -> bool>(&mut self, p: P) {
+        self.as_mut().retain(p)
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadMut<O, V, F> {
+    /// Replaces the projection, keeping the owner in place.
+    pub fn with_func<V2: ?Sized, G: FnMut(&O) -> &V2>(self, g: G) -> SelfMonadMut<O, V2, G> {
+        SelfMonadMut::new(self.owner_into(), g)
+    }
+
+    /// Splits the monad into its owner and its projection.
+    pub fn into_parts(self) -> (O, F) {
+        (self.owner, self.func.into_inner())
+    }
+
+    /// Clones the projection out of the monad so it can be reused over another owner.
+    /// Works with function pointers and any `Clone` closure.
+    pub fn clone_projection(&self) -> F where F: Clone {
+        self.func.borrow().clone()
+    }
+
+    /// Moves the owner and the projection into a `SelfMonad`, dropping the `RefCell` once the
+    /// projection no longer needs mutable state. The inverse of `SelfMonad::into_mut`.
+    pub fn into_immutable(self) -> SelfMonad<O, V, F> where F: Fn(&O) -> &V {
+        SelfMonad::new(self.owner, self.func.into_inner())
+    }
+
+    /// Returns the address of the owner, for identity checks.
+    pub fn owner_ptr(&self) -> *const O {
+        &self.owner
+    }
+
+    /// Returns how many projections of this monad are running. From inside the projection it is at
+    /// least one, and re-entering `as_ref` there would panic. Only debug builds keep the count, release
+    /// builds always return 0.
+    pub fn debug_borrow_depth(&self) -> usize {
+        self.depth.get()
+    }
+}
+
+/// A `SelfMonadMut` with a boxed projection, so monads with different closures share one type.
+#[cfg(feature = "alloc")]
+pub type BoxedSelfMonadMut<O, V> = SelfMonadMut<O, V, Box<dyn FnMut(&O) -> &V>>;
+
+#[cfg(feature = "alloc")]
+impl<O, V: ?Sized> BoxedSelfMonadMut<O, V> {
+    /// Boxes the projection into a `BoxedSelfMonadMut`.
+    pub fn box_projection<F: FnMut(&O) -> &V + 'static>(owner: O, func: F) -> Self {
+        SelfMonadMut::new(owner, Box::new(func))
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> Deref for SelfMonadMut<O, V, F> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> AsRef<V> for SelfMonadMut<O, V, F> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        let _depth = self.depth.enter();
+        (*self.func.borrow_mut())(&self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> AsMut<V> for SelfMonadMut<O, V, F> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut V {
+        (self.func.get_mut())(&mut self.owner)
+    }
+}
+
+/// Clones the owner and the current state of the projection.
+///
+/// Panics if called from inside the projection itself, while `func` is mutably borrowed.
+impl<O: Clone, V: ?Sized, F: Clone> Clone for SelfMonadMut<O, V, F> {
+    fn clone(&self) -> Self {
+        SelfMonadMut {
+            owner: self.owner.clone(),
+            func: RefCell::new(self.func.borrow().clone()),
+            depth: BorrowDepth::default(),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O: Default, V: ?Sized, F: Default> Default for SelfMonadMut<O, V, F> {
+    fn default() -> Self {
+        SelfMonadMut {
+            owner: O::default(),
+            func: RefCell::new(F::default()),
+            depth: BorrowDepth::default(),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized + Index<I>, F: FnMut(&O) -> &V, I> Index<I> for SelfMonadMut<O, V, F> {
+    type Output = V::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.as_ref().index(index)
+    }
+}
+
+impl<O, V: ?Sized + Extend<T>, F: FnMut(&mut O) -> &mut V, T> Extend<T> for SelfMonadMut<O, V, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.as_mut().extend(iter)
+    }
+}
+
+impl<O, V: ?Sized + fmt::Write, F: FnMut(&mut O) -> &mut V> fmt::Write for SelfMonadMut<O, V, F> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.as_mut().write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.as_mut().write_char(c)
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        self.as_mut().write_fmt(args)
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> From<(O, F)> for SelfMonadMut<O, V, F> {
+    fn from((owner, func): (O, F)) -> Self {
+        SelfMonadMut::new(owner, func)
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> Borrow<V> for SelfMonadMut<O, V, F> {
+    fn borrow(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized + Debug, F: FnMut(&O) -> &V> Debug for SelfMonadMut<O, V, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // `try_as_ref` releases its borrow of `func` before returning, so nothing is held while
+        // formatting, and formatting from inside the projection shows `<borrowed>` like `RefCell` does
+        let mut tuple = f.debug_tuple("SelfMonadMut");
+        match self.try_as_ref() {
+            Ok(value) => tuple.field(&value),
+            Err(_) => tuple.field(&format_args!("<borrowed>"))
+        };
+        tuple.finish()
+    }
+}
+
+impl<O, V: ?Sized + Display, F: FnMut(&O) -> &V> Display for SelfMonadMut<O, V, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_ref(), f)
+    }
+}
+
+impl<O, V, F, O2, V2, F2> PartialEq<SelfMonadMut<O2, V2, F2>> for SelfMonadMut<O, V, F>
+where
+    V: ?Sized + PartialEq<V2>,
+    V2: ?Sized,
+    F: FnMut(&O) -> &V,
+    F2: FnMut(&O2) -> &V2
+{
+    fn eq(&self, other: &SelfMonadMut<O2, V2, F2>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<O, V: ?Sized + Eq, F: FnMut(&O) -> &V> Eq for SelfMonadMut<O, V, F> {}
+
+impl<O, V: ?Sized + Hash, F: FnMut(&O) -> &V> Hash for SelfMonadMut<O, V, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl<O, F: FnMut(&O) -> &str> PartialEq<str> for SelfMonadMut<O, str, F> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl<'a, O, F: FnMut(&O) -> &str> PartialEq<&'a str> for SelfMonadMut<O, str, F> {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl<O, F: FnMut(&O) -> &str> PartialEq<SelfMonadMut<O, str, F>> for str {
+    fn eq(&self, other: &SelfMonadMut<O, str, F>) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl<O, T, U, F: FnMut(&O) -> &[T]> PartialEq<[U]> for SelfMonadMut<O, [T], F> where T: PartialEq<U> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl<O, T, U, F: FnMut(&O) -> &[U]> PartialEq<SelfMonadMut<O, [U], F>> for [T] where T: PartialEq<U> {
+    fn eq(&self, other: &SelfMonadMut<O, [U], F>) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl<O, F: FnMut(&O) -> &str> PartialOrd<str> for SelfMonadMut<O, str, F> {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other)
+    }
+}
+
+impl<'a, O, F: FnMut(&O) -> &str> PartialOrd<&'a str> for SelfMonadMut<O, str, F> {
+    fn partial_cmp(&self, other: &&'a str) -> Option<Ordering> {
+        self.as_ref().partial_cmp(*other)
+    }
+}
+
+impl<O, F: FnMut(&O) -> &str> PartialOrd<SelfMonadMut<O, str, F>> for str {
+    fn partial_cmp(&self, other: &SelfMonadMut<O, str, F>) -> Option<Ordering> {
+        self.partial_cmp(other.as_ref())
+    }
+}
+
+impl<O, T: PartialOrd, F: FnMut(&O) -> &[T]> PartialOrd<[T]> for SelfMonadMut<O, [T], F> {
+    fn partial_cmp(&self, other: &[T]) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other)
+    }
+}
+
+impl<O, T: PartialOrd, F: FnMut(&O) -> &[T]> PartialOrd<SelfMonadMut<O, [T], F>> for [T] {
+    fn partial_cmp(&self, other: &SelfMonadMut<O, [T], F>) -> Option<Ordering> {
+        self.partial_cmp(other.as_ref())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Builds a `SelfMonad` projecting a field or index path of the owner.
+///
+/// `self_monad!(config => .server.host)` is the same as `SelfMonad::new(config, |o| &o.server.host)`.
+#[macro_export]
+macro_rules! self_monad {
+    ($owner:expr => $($path:tt)+) => {
+        $crate::SelfMonad::new($owner, |o| &o $($path)+)
+    };
+}
+
+/// Stores the owner once and exposes a method per named field or index path of it.
+///
+/// `views!(point; x => .x, y => .y)` builds a value whose `x()` and `y()` return `&point.x` and
+/// `&point.y`.
+#[macro_export]
+macro_rules! views {
+    (@views $owner:expr; [$($name:ident [$($path:tt)+])+]) => {{
+        #[allow(non_camel_case_types, dead_code)]
+        struct Views<O, $($name),+> {
+            owner: O,
+            $($name: $name),+
+        }
+
+        #[allow(non_camel_case_types, dead_code)]
+        impl<O, $($name),+> Views<O, $($name),+> {
+            $(
+                fn $name<V: ?Sized>(&self) -> &V where $name: Fn(&O) -> &V {
+                    (self.$name)(&self.owner)
+                }
+            )+
+        }
+
+        let owner = $owner;
+        $(let $name = $crate::__view(&owner, |o| &o $($path)+);)+
+        Views { owner, $($name),+ }
+    }};
+    (@views $owner:expr; [$($done:tt)*] $name:ident => $($rest:tt)+) => {
+        $crate::views!(@path $owner; [$($done)*] $name []; $($rest)+)
+    };
+    (@path $owner:expr; [$($done:tt)*] $name:ident [$($path:tt)*]; , $($rest:tt)*) => {
+        $crate::views!(@views $owner; [$($done)* $name [$($path)*]] $($rest)*)
+    };
+    (@path $owner:expr; [$($done:tt)*] $name:ident [$($path:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::views!(@path $owner; [$($done)*] $name [$($path)* $next]; $($rest)*)
+    };
+    (@path $owner:expr; [$($done:tt)*] $name:ident [$($path:tt)*];) => {
+        $crate::views!(@views $owner; [$($done)* $name [$($path)*]])
+    };
+    ($owner:expr; $($rest:tt)+) => {
+        $crate::views!(@views $owner; [] $($rest)+)
+    };
+}
+
+/// Pins the signature of a `views!` projection, so it borrows from the owner it is given.
+#[doc(hidden)]
+pub fn __view<O, V: ?Sized, F: Fn(&O) -> &V>(_owner: &O, func: F) -> F {
+    func
+}
+
+//--------------------------------------------------------------------------------------------------
+
+pub trait SelfMonadOwner<O> {
+    fn owner(&self) -> &O;
+    fn owner_mut(&mut self) -> &mut O;
+    fn owner_into(self) -> O;
+
+    /// Puts `new` in place of the owner and returns the old one.
+    ///
+    /// Goes through `owner_mut`, so monads caching their value, like `SelfMonadOwnedCached`, drop
+    /// the cache. A `SelfMonadOnce` whose projection has already run can't project the new owner.
+    fn replace_owner(&mut self, new: O) -> O {
+        mem::replace(self.owner_mut(), new)
+    }
+
+    /// Transforms the owner by value in place.
+    ///
+    /// The owner is taken out with `O::default()` left behind, so a panicking `g` leaves the default
+    /// owner in the monad. Goes through `owner_mut`, so monads caching their value drop the cache.
+    fn update_owner<G: FnOnce(O) -> O>(&mut self, g: G) where O: Default {
+        let owner = self.owner_mut();
+        *owner = g(mem::take(owner));
+    }
+
+    /// Lends the owner mutably to `g` and returns its result. Projections can run again once `g`
+    /// returns.
+    ///
+    /// Goes through `owner_mut`, so monads caching their value, like `SelfMonadOwnedCached`, drop the
+    /// cache and compute it from the changed owner.
+    fn owner_mut_then<R, G: FnOnce(&mut O) -> R>(&mut self, g: G) -> R {
+        g(self.owner_mut())
+    }
+
+    /// Consumes the monad and iterates its owner by value, e.g. the elements of a `Vec` owner.
+    fn into_owner_iter(self) -> O::IntoIter where O: IntoIterator, Self: Sized {
+        self.owner_into().into_iter()
+    }
+
+    /// Compares the owners of two monads, where `==` compares their projected values.
+    fn eq_by_owner<M: SelfMonadOwner<O> + ?Sized>(&self, other: &M) -> bool where O: PartialEq {
+        self.owner() == other.owner()
+    }
+
+    /// Orders two monads by their owners, where `cmp` orders them by their projected values.
+    fn cmp_by_owner<M: SelfMonadOwner<O> + ?Sized>(&self, other: &M) -> Ordering where O: Ord {
+        self.owner().cmp(other.owner())
+    }
+
+    /// Reaches through an owner that is itself a smart pointer, like `Box` or `Rc`.
+    fn owner_deref<'a>(&'a self) -> &'a O::Target where O: Deref + 'a {
+        self.owner()
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOwner<O> for SelfMonadOnce<O, V, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    /// Returns the owner whether or not the projection has run. No projected reference can outlive
+    /// the `self` taken here, so the owner comes back with no borrows into it.
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOwner<O> for SelfMonad<O, V, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOwner<O> for SelfMonadMut<O, V, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+// Tests -------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test_once {
+    use crate::{SelfMonadOnce, SelfMonadOwner};
+    #[cfg(feature = "alloc")]
+    use crate::BoxedSelfMonadOnce;
+    use std::borrow::{Borrow, BorrowMut};
+    use std::ops::Deref;
+    use std::{mem, thread};
+
+    #[test]
+    fn once_pointer() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn once_closure() {
+        let c: Box<dyn FnOnce(&String) -> &str> = Box::new(|s| &s[0..2]);
+        let m = SelfMonadOnce::new(String::from("hello"), c);
+        assert_eq!("he", m.deref());
+    }
+
+    #[test]
+    fn once_as_ref() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.as_ref());
+    }
+
+    #[test]
+    fn once_as_ref_unchecked() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        let value = unsafe { m.as_ref_unchecked() };
+        assert_eq!("he", value);
+        assert!(m.is_consumed());
+        assert_eq!(None, m.try_as_ref());
+    }
+
+    #[test]
+    fn once_closure_as_mut() {
+        let c: Box<dyn FnOnce(&mut String) -> &mut str> = Box::new(|s| s[0..2].borrow_mut());
+        let mut m = SelfMonadOnce::new_mut(String::from("hello"), c);
+        assert_eq!("he", m.as_mut());
+    }
+
+    #[test]
+    fn once_is_consumed() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert!(!m.is_consumed());
+        assert!(!m.is_consumed());
+        assert_eq!("he", &*m);
+        assert!(m.is_consumed());
+    }
+
+    #[test]
+    fn once_debug_does_not_consume() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("SelfMonadOnce(<unconsumed>)", format!("{:?}", m));
+        assert_eq!("he", &*m);
+        assert_eq!("SelfMonadOnce(<consumed>)", format!("{:?}", m));
+    }
+
+    #[test]
+    #[should_panic(expected = "projection has already run")]
+    fn once_deref_twice() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.deref());
+        let _ = m.deref();
+    }
+
+    #[test]
+    fn once_try_as_ref() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!(Some("he"), m.try_as_ref());
+        assert!(m.is_consumed());
+        assert_eq!(None, m.try_as_ref());
+    }
+
+    #[test]
+    fn once_try_as_mut() {
+        let mut m = SelfMonadOnce::new_mut(String::from("hello"), |s| &mut s[0..2]);
+        m.try_as_mut().unwrap().make_ascii_uppercase();
+        assert!(m.is_consumed());
+        assert_eq!(None, m.try_as_mut());
+        assert_eq!("HEllo", m.owner());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn once_box_projection() {
+        let monads: Vec<BoxedSelfMonadOnce<String, str>> = vec![
+            BoxedSelfMonadOnce::box_projection(String::from("hello"), |s| &s[1..]),
+            BoxedSelfMonadOnce::box_projection(String::from("world"), |s| s.as_str())
+        ];
+        let values: Vec<&str> = monads.iter().map(|m| m.as_ref()).collect();
+        assert_eq!(vec!["ello", "world"], values);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn once_fuse() {
+        let m = SelfMonadOnce::new([1, 2, 3, 4], |a| &a[1..3]);
+        let fused = m.fuse().unwrap();
+        assert_eq!([2, 3], **fused);
+        assert_eq!([2, 3], **fused);
+
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        assert!(m.fuse().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn once_fuse_move_box_owner() {
+        let m = SelfMonadOnce::new(Box::new([1, 2, 3, 4]), |b| &b[1..3]);
+        let fused = m.fuse().unwrap();
+        let cached: *const [i32] = &**fused;
+        assert_eq!([2, 3], **fused);
+
+        let mut moved = vec![fused];
+        let fused = moved.pop().unwrap();
+        assert!(std::ptr::eq(cached, &**fused));
+        assert_eq!([2, 3], **fused);
+        assert_eq!(4, fused.owner()[3]);
+    }
+
+    #[test]
+    fn once_default() {
+        #[derive(Default)]
+        struct Unset;
+
+        let m: SelfMonadOnce<String, str, Unset> = Default::default();
+        assert!(!m.is_consumed());
+        let m = m.with_func(|s| s.as_str());
+        assert_eq!("", &*m);
+    }
+
+    #[test]
+    fn once_ptr() {
+        let m = SelfMonadOnce::new([1, 2, 3, 4], |a| &a[1..3]);
+        assert!(std::ptr::eq(m.owner(), m.owner_ptr()));
+        assert_eq!(m.owner_ptr() as *const i32, unsafe { (m.value_ptr() as *const i32).sub(1) });
+    }
+
+    #[test]
+    fn once_borrowed_owner() {
+        let text = String::from("hello world");
+        let mut m = SelfMonadOnce::new(text.as_str(), |s| &s[..5]);
+        assert_eq!("hello world", m.replace_owner("goodbye"));
+        assert_eq!("goodb", &*m);
+
+        let mut bytes = [1u8, 2, 3];
+        let mut m = SelfMonadOnce::new_mut(&mut bytes[..], |b| &mut b[1..]);
+        m.as_mut()[0] = 7;
+        m.owner_into();
+        assert_eq!([1, 7, 3], bytes);
+    }
+
+    #[test]
+    fn once_with_value() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[1..]);
+        assert_eq!(4, m.with_value(|v| v.len()));
+
+        let mut m = SelfMonadOnce::new_mut(vec![1, 2, 3], |v| &mut v[1..]);
+        assert_eq!(10, m.with_value_mut(|v| {
+            v[0] = 7;
+            v.iter().sum::<i32>()
+        }));
+        assert_eq!(&vec![1, 7, 3], m.owner());
+    }
+
+    #[test]
+    fn once_owner_into_after_deref() {
+        let m = SelfMonadOnce::new([1, 2, 3, 4], |a| &a[1..3]);
+        assert_eq!([2, 3], *m);
+        let mut owner = m.owner_into();
+        owner[1] = 7;
+        assert_eq!([1, 7, 3, 4], owner);
+
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        let mut owner = m.owner_into();
+        owner.clear();
+        owner.push_str("world");
+        assert_eq!("world", owner);
+    }
+
+    #[test]
+    fn once_into_parts() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        let (owner, func) = m.into_parts();
+        let m = SelfMonadOnce::new(owner, func.unwrap());
+        assert_eq!("he", &*m);
+
+        let (owner, func) = m.into_parts();
+        assert_eq!("hello", owner);
+        assert!(func.is_none());
+    }
+
+    #[test]
+    fn once_value() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.value());
+        assert!(m.is_consumed());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn once_to_owned_value() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!(String::from("he"), m.to_owned_value());
+    }
+
+    #[test]
+    fn once_value_mut() {
+        let mut m = SelfMonadOnce::new_mut(vec![1, 2, 3], |v| &mut v[1..]);
+        m.value_mut().copy_from_slice(&[5, 6]);
+        assert_eq!(&vec![1, 5, 6], m.owner());
+    }
+
+    #[test]
+    fn once_with_func() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        let m = m.with_func(|s| &s[1..3]);
+        assert!(!m.is_consumed());
+        assert_eq!("el", &*m);
+    }
+
+    #[test]
+    fn once_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SelfMonadOnce<String, str, fn(&String) -> &str>>();
+
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", thread::spawn(move || m.to_string()).join().unwrap());
+    }
+
+    #[test]
+    fn once_replace_owner() {
+        let mut m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("hello", m.replace_owner(String::from("world")));
+        assert_eq!("wo", &*m);
+
+        assert_eq!("world", m.replace_owner(String::from("again")));
+        assert_eq!(None, m.try_as_ref());
+    }
+
+    #[test]
+    fn once_update_owner() {
+        let mut m = SelfMonadOnce::new(String::from("hello"), |s| &s[3..]);
+        m.update_owner(|mut s| {
+            s.push_str(" world");
+            s
+        });
+        assert_eq!("lo world", &*m);
+    }
+
+    #[test]
+    fn once_owner_mut_then() {
+        let mut m = SelfMonadOnce::new(String::from("hello"), |s| &s[3..]);
+        let len = m.owner_mut_then(|s| {
+            s.push_str(" world");
+            s.len()
+        });
+        assert_eq!(11, len);
+        assert_eq!("lo world", &*m);
+
+        m.owner_mut_then(|s| s.clear());
+        assert_eq!(None, m.try_as_ref());
+    }
+
+    #[test]
+    fn once_deref_after_move() {
+        let m = SelfMonadOnce::new([1, 2, 3, 4], |a| &a[1..3]);
+        let moved = Box::new(m);
+        let start = moved.owner() as *const [i32; 4] as usize;
+        let value = moved.as_ref().as_ptr() as usize;
+        assert_eq!(start + mem::size_of::<i32>(), value);
+    }
+
+    #[test]
+    fn once_move_box_owner() {
+        let m = SelfMonadOnce::new(Box::new([1, 2, 3, 4]), |b| &b[1..3]);
+        let mut moved = vec![m];
+        let m = moved.pop().unwrap();
+        assert_eq!([2, 3], *m);
+
+        let m = Box::new(m);
+        assert_eq!(None, m.try_as_ref());
+        assert_eq!(4, m.owner()[3]);
+    }
+
+    #[test]
+    fn once_move_box_owner_mut() {
+        let mut m = SelfMonadOnce::new_mut(Box::new([1, 2, 3, 4]), |b| &mut b[1..3]);
+        m.as_mut()[0] = 5;
+
+        let mut m = Box::new(m);
+        assert_eq!(None, m.try_as_mut());
+        assert_eq!([1, 5, 3, 4], *m.owner_into());
+    }
+
+    #[test]
+    fn once_owner_mut_after_deref() {
+        let mut m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        m.owner_mut().push_str(" world");
+        assert_eq!(None, m.try_as_ref());
+        assert_eq!("hello world", m.owner_into());
+    }
+
+    #[test]
+    fn once_borrow() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        let value: &str = Borrow::borrow(&m);
+        assert_eq!("he", value);
+        assert!(m.is_consumed());
+    }
+
+    #[test]
+    fn once_from_tuple() {
+        let m: SelfMonadOnce<_, _, _> = (String::from("hello"), (|s| &s[0..2]) as fn(&String) -> &str).into();
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn once_display() {
+        let m = SelfMonadOnce::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.to_string());
+        assert!(m.is_consumed());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{from_array, monads_from_iter, SelfMonad, SelfMonadMut, SelfMonadOwner};
+    #[cfg(feature = "alloc")]
+    use crate::BoxedSelfMonad;
+    use std::borrow::{Borrow, BorrowMut};
+    use std::collections::HashMap;
+    use std::cmp::Ordering;
+    use std::fmt::{Debug, Display};
+    use std::thread;
+
+    #[test]
+    fn pointer_twice() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn closure() {
+        let c: Box<dyn Fn(&String) -> &str> = Box::new(|s| &s[0..2]);
+        let m = SelfMonad::new(String::from("hello"), c);
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn closure_as_mut() {
+        let c: Box<dyn Fn(&mut String) -> &mut str> = Box::new(|s| s[0..2].borrow_mut());
+        let mut m = SelfMonad::new_mut(String::from("hello"), c);
+        assert_eq!("he", m.as_mut());
+    }
+
+    #[test]
+    fn as_ref() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.as_ref());
+    }
+
+    #[test]
+    fn debug() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("SelfMonad(\"he\")", format!("{:?}", m));
+        assert_eq!("SelfMonad(\n    \"he\",\n)", format!("{:#?}", m));
+    }
+
+    #[test]
+    fn debug_slice() {
+        let m = SelfMonad::new(vec![1, 2, 3], |v| &v[1..]);
+        assert_eq!("SelfMonad([2, 3])", format!("{:?}", m));
+    }
+
+    #[test]
+    fn debug_derived() {
+        #[derive(Debug)]
+        struct Point {
+            x: i32,
+            y: i32
+        }
+
+        type First = fn(&Vec<Point>) -> &Point;
+
+        #[derive(Debug)]
+        struct Holder {
+            first: SelfMonad<Vec<Point>, Point, First>
+        }
+
+        let h = Holder { first: SelfMonad::new(vec![Point { x: 1, y: 2 }], |v| &v[0]) };
+        assert_eq!(3, h.first.x + h.first.y);
+        assert_eq!("Holder { first: SelfMonad(Point { x: 1, y: 2 }) }", format!("{:?}", h));
+    }
+
+    #[test]
+    fn display() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.to_string());
+        assert_eq!("[he  ]", format!("[{:<4}]", m));
+    }
+
+    #[test]
+    fn eq_str() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!(m, *"he");
+        assert_eq!(*"he", m);
+        assert_ne!(m, *"hel");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn eq_str_ref_and_string() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert!(m == "he");
+        assert!(m != "hel");
+        let (he, hel) = (String::from("he"), String::from("hel"));
+        assert!(m == he);
+        assert!(m != hel);
+    }
+
+    #[test]
+    fn cmp_raw() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[1..3]);
+        assert!(m < "fa");
+        assert!(m > "ea");
+        assert!(m == "el");
+        assert!(*"fa" > m);
+        assert!(*"ea" < m);
+
+        let m = SelfMonad::new(vec![1, 2, 3], |v| &v[1..]);
+        assert!(m < [2, 4][..]);
+        assert!(m > [2][..]);
+        assert!([3][..] > m);
+    }
+
+    #[test]
+    fn eq_slice() {
+        let m = SelfMonad::new(vec![1, 2, 3], |v| &v[1..]);
+        assert_eq!(m, [2, 3][..]);
+        assert_eq!([2, 3][..], m);
+        assert_ne!(m, [1, 2][..]);
+    }
+
+    #[test]
+    fn eq_monads() {
+        let m1 = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = SelfMonad::new(Box::new("ahead"), |s| &s[1..3]);
+        let m3 = SelfMonad::new(vec![1, 2, 3], |v| &v[1..]);
+        let m4 = SelfMonad::new(vec![3, 2, 3], |v| &v[1..]);
+        assert_eq!(m1, m2);
+        assert_eq!(m3, m4);
+        assert_ne!(m1, SelfMonad::new(String::from("hello"), |s| &s[1..3]));
+    }
+
+    #[test]
+    fn hash_map_key() {
+        type Key = SelfMonad<String, str, fn(&String) -> &str>;
+
+        let mut map = HashMap::new();
+        map.insert(Key::new(String::from("hello"), |s| &s[0..2]), 1);
+        map.insert(Key::new(String::from("ahead"), |s| &s[1..3]), 2);
+        assert_eq!(1, map.len());
+        assert_eq!(Some(&2), map.get(&Key::new(String::from("he"), |s| s)));
+    }
+
+    #[test]
+    fn map() {
+        let m = SelfMonad::new(String::from("hello world"), |s| &s[6..]).map(|s| &s[0..3]);
+        assert_eq!("wor", &*m);
+        assert_eq!("wor", &*m);
+        assert_eq!("hello world", m.owner());
+    }
+
+    #[test]
+    fn try_map() {
+        let mut m = SelfMonad::new(vec![b'h', b'i', b'!'], |v| &v[0..2]).try_map(str::from_utf8);
+        assert_eq!(Ok("hi"), m.try_as_ref());
+        m.owner_mut()[1] = 0xff;
+        assert!(m.try_as_ref().is_err());
+    }
+
+    #[test]
+    fn project_to_dyn() {
+        struct Registry {
+            handler: Box<u32>
+        }
+
+        let m: SelfMonad<_, dyn Display, _> =
+            SelfMonad::new(Registry { handler: Box::new(42) }, |r| &*r.handler as &dyn Display);
+        assert_eq!("42", m.to_string());
+        assert_eq!("42", (*m).to_string());
+        assert_eq!("42", AsRef::<dyn Display>::as_ref(&m).to_string());
+    }
+
+    #[test]
+    fn as_dyn() {
+        let m = SelfMonad::new((String::from("id"), Box::new(7_u8)), |t| &*t.1);
+        let display = m.as_dyn::<dyn Display>(|v| v);
+        assert_eq!("7", display.to_string());
+        let debug: &dyn Debug = m.as_dyn(|v| v);
+        assert_eq!("7", format!("{:?}", debug));
+    }
+
+    #[test]
+    fn map_field() {
+        struct Section {
+            name: String
+        }
+
+        struct Config {
+            section: Section
+        }
+
+        let config = Config { section: Section { name: String::from("server") } };
+        let m = SelfMonad::new(config, |c| &c.section).map(|s| s.name.as_str());
+        assert_eq!("server", &*m);
+    }
+
+    #[test]
+    fn and_then() {
+        let m = SelfMonad::new(String::from("hello world"), |s| s.as_str())
+            .and_then(|s| &s[6..])
+            .and_then(|s| &s[1..4]);
+        assert_eq!("orl", &*m);
+        assert_eq!("orl", &*m);
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SelfMonad<String, str, fn(&String) -> &str>>();
+        assert_send_sync::<SelfMonad<Vec<u8>, [u8], fn(&Vec<u8>) -> &[u8]>>();
+
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", thread::spawn(move || m.to_string()).join().unwrap());
+    }
+
+    #[test]
+    fn flatten() {
+        struct Document<F> {
+            title: SelfMonad<String, str, F>
+        }
+
+        let title = SelfMonad::new(String::from("# Title"), |s| &s[2..]);
+        let m = SelfMonad::new(Document { title }, |d| &d.title).flatten();
+        assert_eq!("Title", &*m);
+        assert_eq!("# Title", m.owner().title.owner());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_owned_value() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let value: String = m.to_owned_value();
+        drop(m);
+        assert_eq!("he", value);
+
+        let m = SelfMonad::new(vec![1, 2, 3], |v| &v[1..]);
+        assert_eq!(vec![2, 3], m.to_owned_value());
+    }
+
+    #[test]
+    fn map_owner() {
+        struct Wrapper(String);
+
+        impl AsRef<String> for Wrapper {
+            fn as_ref(&self) -> &String {
+                &self.0
+            }
+        }
+
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]).map_owner(Wrapper);
+        assert_eq!("he", &*m);
+        assert_eq!("hello", m.owner().0);
+    }
+
+    #[test]
+    fn inspect() {
+        let mut calls = Vec::new();
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]).inspect(|v| calls.push(v.to_string()));
+        assert_eq!(vec!["he"], calls);
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn reborrow() {
+        let m = SelfMonad::new(String::from("hello world"), |s| &s[6..]);
+        let sub = m.reborrow(|s| &s[1..3]);
+        assert_eq!("or", &*sub);
+        assert_eq!("world", *sub.owner());
+        assert_eq!("world", &*m);
+    }
+
+    #[test]
+    fn into_mut() {
+        let mut m = SelfMonad::new_mut(vec![1, 2, 3], |v| &mut v[1..]).into_mut();
+        m.as_mut()[0] = 5;
+        assert_eq!(&vec![1, 5, 3], m.owner());
+
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]).into_mut();
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn replace_owner() {
+        let mut m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("hello", m.replace_owner(String::from("world")));
+        assert_eq!("wo", &*m);
+    }
+
+    #[test]
+    fn owner_deref() {
+        let m = SelfMonad::new(Box::new(String::from("hello")), |o| &o[1..]);
+        let owner: &String = m.owner_deref();
+        assert_eq!("hello", owner);
+        assert_eq!(5, m.owner_deref().len());
+        assert_eq!("ello", &*m);
+    }
+
+    #[test]
+    fn update_owner() {
+        let mut m = SelfMonad::new(String::from("hello"), |s| &s[3..]);
+        m.update_owner(|s| s + " world");
+        assert_eq!("lo world", &*m);
+    }
+
+    #[test]
+    fn into_owner_iter() {
+        let m = SelfMonad::new(vec![String::from("a"), String::from("b")], |v| &v[1..]);
+        assert_eq!(1, m.len());
+        let owned: Vec<String> = m.into_owner_iter().collect();
+        assert_eq!(vec![String::from("a"), String::from("b")], owned);
+
+        let m: SelfMonad<Vec<i32>, [i32], _> = SelfMonad::new(vec![1, 2, 3], |v| &v[..]);
+        assert_eq!(6, m.into_owner_iter().sum::<i32>());
+    }
+
+    #[test]
+    fn eq_and_cmp_by_owner() {
+        let a = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let b = SelfMonad::new(String::from("help"), |s| &s[0..2]);
+        assert!(a == b);
+        assert!(!a.eq_by_owner(&b));
+        assert_eq!(Ordering::Equal, a.partial_cmp(&b).unwrap());
+        assert_eq!(Ordering::Less, a.cmp_by_owner(&b));
+
+        let c = SelfMonadMut::new(String::from("hello"), |s| &s[1..]);
+        assert!(a.eq_by_owner(&c));
+        assert_eq!(Ordering::Equal, c.cmp_by_owner(&a));
+    }
+
+    #[test]
+    fn from_ref() {
+        let owner = String::from("hello world");
+        let m = SelfMonad::from_ref(&owner, |s| &s[6..]);
+        assert_eq!("world", &*m);
+        assert_eq!("hello world", *m.owner());
+        assert_eq!(11, owner.len());
+        assert_eq!("hello", &owner[..5]);
+    }
+
+    #[test]
+    fn fn_pointer_adds_no_size() {
+        type Monad = SelfMonad<String, str, fn(&String) -> &str>;
+        assert_eq!(std::mem::size_of::<(String, fn(&String) -> &str)>(), std::mem::size_of::<Monad>());
+    }
+
+    #[test]
+    fn new_const() {
+        type Header = (u16, [u8; 3]);
+        type Magic = fn(&Header) -> &[u8];
+
+        fn magic(header: &Header) -> &[u8] {
+            &header.1
+        }
+
+        static HEADER: SelfMonad<Header, [u8], Magic> = SelfMonad::new_const((1, *b"ELF"), magic);
+        assert_eq!(b"ELF", &*HEADER);
+        assert_eq!(1, HEADER.owner().0);
+    }
+
+    #[test]
+    fn owner_mut_then() {
+        let mut m = SelfMonad::new(vec![3, 1, 2], |v| &v[1..]);
+        assert_eq!([1, 2], *m);
+        let first = m.owner_mut_then(|v| {
+            v.sort();
+            v[0]
+        });
+        assert_eq!(1, first);
+        assert_eq!([2, 3], *m);
+    }
+
+    #[test]
+    fn as_str_and_as_bytes() {
+        fn len(s: impl AsRef<str>) -> usize {
+            s.as_ref().len()
+        }
+        fn first(b: impl AsRef<[u8]>) -> u8 {
+            b.as_ref()[0]
+        }
+
+        let m = SelfMonad::new((1, String::from("hello")), |t| &t.1);
+        assert_eq!(5, len(m.as_str()));
+        assert_eq!(b'h', first(m.as_bytes()));
+
+        let m = SelfMonad::new(vec![String::from("hello")], |v| &v[0][1..]);
+        assert_eq!("ello", m.as_str());
+        assert_eq!(b"ello", m.as_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn box_projection() {
+        let monads: Vec<BoxedSelfMonad<String, str>> = vec![
+            BoxedSelfMonad::box_projection(String::from("hello"), |s| &s[..2]),
+            BoxedSelfMonad::box_projection(String::from("world"), |s| s.trim_start_matches('w'))
+        ];
+        let values: Vec<&str> = monads.iter().map(|m| m.as_ref()).collect();
+        assert_eq!(vec!["he", "orld"], values);
+    }
+
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Unset;
+
+        #[derive(Default)]
+        struct Holder {
+            name: SelfMonad<String, str, Unset>
+        }
+
+        let holder = Holder::default();
+        assert_eq!("", holder.name.owner());
+        let mut m = holder.name.with_func(|s| s.as_str());
+        m.owner_mut().push_str("hello");
+        assert_eq!("hello", &*m);
+    }
+
+    #[test]
+    fn ptr() {
+        let m1 = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = m1.clone();
+        assert_ne!(m1.owner_ptr(), m2.owner_ptr());
+        assert_ne!(m1.value_ptr(), m2.value_ptr());
+        assert_eq!(m1.value_ptr(), m1.value_ptr());
+        assert_eq!(m1.owner().as_ptr(), m1.value_ptr() as *const u8);
+    }
+
+    #[test]
+    fn filter() {
+        let m = SelfMonad::new(vec![1, 2, 3], |v| &v[1..]).filter(|s| !s.is_empty());
+        assert_eq!(Some(&[2, 3][..]), m.get());
+
+        let m = SelfMonad::new(vec![1], |v| &v[1..]).filter(|s| !s.is_empty());
+        assert_eq!(None, m.get());
+    }
+
+    #[test]
+    fn borrowed_owner() {
+        let text = String::from("hello world");
+        let mut m = SelfMonad::new(text.as_str(), |s| &s[6..]);
+        assert_eq!("world", &*m);
+        *m.owner_mut() = "good morning";
+        assert_eq!("orning", &*m);
+        let owner: &str = m.owner_into();
+        assert_eq!("good morning", owner);
+
+        let bytes = [1u8, 2, 3, 4];
+        let m = SelfMonad::new(&bytes[..], |b| &b[2..]);
+        assert_eq!([3, 4], *m);
+
+        let mut bytes = [1u8, 2, 3, 4];
+        let mut m = SelfMonad::new_mut(&mut bytes[..], |b| &mut b[2..]);
+        m.as_mut().reverse();
+        m.owner_into();
+        assert_eq!([1, 2, 4, 3], bytes);
+    }
+
+    #[test]
+    fn array() {
+        let m: SelfMonad<[u8; 4], [u8], _> = SelfMonad::new([1, 2, 3, 4], |a| &a[1..3]);
+        assert_eq!([2, 3], *m);
+        assert_eq!(3, m[1]);
+
+        let m = from_array([1, 2, 3, 4, 5], 1..4);
+        assert_eq!([2, 3, 4], *m);
+        assert_eq!(4, m[2]);
+        assert_eq!([3, 4], m[1..]);
+        let m = Box::new(m);
+        assert_eq!([2, 3, 4], **m);
+
+        assert_eq!([4, 5], *from_array([1, 2, 3, 4, 5], 3..));
+        assert_eq!([1, 2], *from_array([1, 2, 3, 4, 5], ..2));
+        assert!(from_array([1, 2, 3, 4, 5], 2..2).is_empty());
+    }
+
+    #[test]
+    fn from_iter() {
+        let owners = vec![String::from("hello"), String::from("world")];
+        let monads: Vec<_> = monads_from_iter(owners, |s: &String| &s[1..3]).collect();
+        let values: Vec<&str> = monads.iter().map(|m| m.as_ref()).collect();
+        assert_eq!(vec!["el", "or"], values);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_deref_box() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let boxed: Box<dyn std::ops::Deref<Target = str>> = m.into_deref_box();
+        assert_eq!("he", &**boxed);
+        assert_eq!(2, boxed.len());
+    }
+
+    #[test]
+    fn with_value() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[1..]);
+        assert_eq!(4, m.with_value(|v| v.len()));
+
+        let mut m = SelfMonad::new_mut(vec![1, 2, 3], |v| &mut v[1..]);
+        m.with_value_mut(|v| v.reverse());
+        assert_eq!(&vec![1, 3, 2], m.owner());
+    }
+
+    #[test]
+    fn new_checked() {
+        let m = SelfMonad::new_checked((1u8, [2u16, 3, 4]), |t| &t.1[1..]);
+        assert_eq!([3, 4], *m);
+        let m = SelfMonad::new_checked([1, 2, 3], |a| &a[2]);
+        assert_eq!(3, *m);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "projection does not point into the owner")]
+    fn new_checked_outside_owner() {
+        SelfMonad::new_checked([1, 2, 3], |_| "elsewhere");
+    }
+
+    #[test]
+    fn into_parts() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let (owner, func) = m.into_parts();
+        assert_eq!("hello", owner);
+        let m = SelfMonadMut::new(owner + " world", func);
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn clone_projection() {
+        let first = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let second = SelfMonad::new(String::from("world"), first.clone_projection());
+        assert_eq!("he", &*first);
+        assert_eq!("wo", &*second);
+
+
+        let head = SelfMonad::new(String::from("abc"), (|s| &s[0..1]) as fn(&String) -> &str);
+        let other = SelfMonad::new(String::from("xyz"), head.clone_projection());
+        assert_eq!("x", &*other);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_new() {
+        let m = SelfMonad::try_new(String::from("hello"), |s| &s[0..2]).unwrap();
+        assert_eq!("he", &*m);
+
+        let result = SelfMonad::try_new(vec![1, 2, 3], |v| &v[2..5]);
+        assert!(result.unwrap_err().message().contains("out of range"));
+    }
+
+    #[test]
+    fn swap_value() {
+        let mut m = SelfMonad::new_mut((String::from("counter"), 1), |t| &mut t.1);
+        assert_eq!(1, m.swap_value(5));
+        assert_eq!(5, m.swap_value(7));
+        assert_eq!(7, m.owner().1);
+    }
+
+    #[test]
+    fn then_project() {
+        let m = SelfMonad::new(String::from("key=value;rest"), |s| &s[4..]);
+        let value = m.then_project(|s| s.split(';').next().unwrap());
+        let last = value.then_project(|s| &s[s.len() - 1..]);
+        assert_eq!("value", &*value);
+        assert_eq!("e", &*last);
+        assert_eq!("value;rest", &*m);
+    }
+
+    #[test]
+    fn value() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.value());
+    }
+
+    #[test]
+    fn owner_and_value() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let (owner, value) = m.owner_and_value();
+        assert_eq!("hello", owner);
+        assert_eq!("he", value);
+    }
+
+    #[test]
+    fn with_func() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        let m = m.with_func(|s| &s[1..3]);
+        assert_eq!("el", &*m);
+        let m = m.with_func(|s| s.as_bytes());
+        assert_eq!(b"hello", &*m);
+    }
+
+    #[test]
+    fn value_mut() {
+        let mut m = SelfMonad::new_mut(String::from("hello"), |s| s.as_mut_str());
+        m.value_mut().make_ascii_uppercase();
+        assert_eq!("HELLO", m.owner());
+    }
+
+    #[test]
+    fn macro_fields() {
+        struct Server {
+            host: String
+        }
+
+        struct Config {
+            servers: Vec<Server>
+        }
+
+        let config = Config { servers: vec![Server { host: String::from("localhost") }] };
+        let m = self_monad!(config => .servers[0].host);
+        assert_eq!("localhost", m.as_str());
+    }
+
+    #[test]
+    fn macro_views() {
+        struct Config {
+            host: String,
+            ports: Vec<u16>,
+            tags: [&'static str; 2]
+        }
+
+        let config = Config { host: String::from("localhost"), ports: vec![80, 443], tags: ["web", "tls"] };
+        let v = views!(config; host => .host, port => .ports[1], tags => .tags[..],);
+        assert_eq!("localhost", v.host());
+        assert_eq!(&443, v.port());
+        assert_eq!(["web", "tls"], v.tags());
+        assert_eq!(9, v.host().len());
+
+        let v = views!((1, String::from("one")); number => .0, name => .1);
+        assert_eq!(&1, v.number());
+        assert_eq!("one", v.name());
+    }
+
+    #[test]
+    fn macro_index() {
+        let m = self_monad!(String::from("hello") => [0..2]);
+        assert_eq!("he", &*m);
+
+        let m = self_monad!(vec![1, 2, 3] => [1]);
+        assert_eq!(2, *m);
+    }
+
+    #[test]
+    fn clone() {
+        let m1 = SelfMonad::new(vec![1, 2, 3], (|v| &v[1..]) as fn(&Vec<i32>) -> &[i32]);
+        let mut m2 = m1.clone();
+        m2.owner_mut().push(4);
+        assert_eq!([2, 3], *m1);
+        assert_eq!([2, 3, 4], *m2);
+        assert_eq!(&vec![1, 2, 3], m1.owner());
+    }
+
+    #[test]
+    fn clone_closure() {
+        let skip = 1;
+        let m1 = SelfMonad::new(String::from("hello"), move |s| &s[skip..]);
+        let mut m2 = m1.clone();
+        m2.owner_mut().push('!');
+        assert_eq!("ello", &*m1);
+        assert_eq!("ello!", &*m2);
+    }
+
+    #[test]
+    fn from_tuple() {
+        let m: SelfMonad<_, _, _> = (String::from("hello"), (|s| &s[0..2]) as fn(&String) -> &str).into();
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn index() {
+        let m = SelfMonad::new(vec![1, 2, 3, 4], |v| &v[1..]);
+        assert_eq!(2, m[0]);
+        assert_eq!([3, 4], m[1..]);
+
+        let m = SelfMonad::new(String::from("hello"), |s| &s[1..]);
+        assert_eq!("el", &m[0..2]);
+    }
+
+    #[test]
+    fn iterate() {
+        let m = SelfMonad::new(vec![1, 2, 3, 4], |v| &v[1..]);
+        let mut sum = 0;
+        for x in &m {
+            sum += x;
+        }
+        assert_eq!(9, sum);
+        assert_eq!(9, (&m).into_iter().sum::<i32>());
+    }
+
+    #[test]
+    fn iterate_bytes() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s.as_bytes()[0..2]);
+        assert_eq!(vec![b'h', b'e'], (&m).into_iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn borrow() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let value: &str = Borrow::borrow(&m);
+        assert_eq!("he", value);
+    }
+
+    #[test]
+    fn borrow_map_lookup() {
+        let mut map = HashMap::new();
+        map.insert(SelfMonad::new(String::from("hello"), (|s| &s[0..2]) as fn(&String) -> &str), 1);
+        assert_eq!(Some(&1), map.get("he"));
+        assert_eq!(None, map.get("hello"));
+    }
+
+    #[test]
+    fn cmp_monads() {
+        let m1 = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = SelfMonad::new(Box::new("world"), |s| &s[1..3]);
+        assert!(m1 < m2);
+        assert!(m2 >= m1);
+        assert_eq!(Some(Ordering::Less), m1.partial_cmp(&m2));
+    }
+
+    #[test]
+    fn sort() {
+        let words = ["pear", "apple", "fig", "banana"];
+        let mut monads: Vec<_> = words.iter()
+            .map(|w| SelfMonad::new(w.to_string(), (|s| &s[1..]) as fn(&String) -> &str))
+            .collect();
+        monads.sort();
+
+        let mut expected: Vec<_> = words.iter().map(|w| &w[1..]).collect();
+        expected.sort();
+        assert_eq!(expected, monads.iter().map(|m| &**m).collect::<Vec<_>>());
+    }
+}
+
+#[cfg(test)]
+mod test_mut {
+    use crate::{SelfMonadMut, SelfMonadOwner};
+    #[cfg(feature = "alloc")]
+    use crate::BoxedSelfMonadMut;
+    use std::borrow::{Borrow, BorrowMut};
+    use std::cell::RefCell;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn mut_pointer_twice() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn mut_try_as_ref_reentrant() {
+        use std::cell::Cell;
+
+        let probe: Cell<Option<&dyn Fn() -> bool>> = Cell::new(None);
+        let reentered = Cell::new(None);
+        let m = SelfMonadMut::new(String::from("hello"), |s| {
+            reentered.set(probe.get().map(|probe| probe()));
+            &s[0..2]
+        });
+        let reenter = || m.try_as_ref().is_err();
+        probe.set(Some(&reenter));
+
+        assert_eq!(Some("he"), m.try_as_ref().ok());
+        assert_eq!(Some(true), reentered.get());
+    }
+
+    #[test]
+    fn mut_closure() {
+        let c: Box<dyn FnMut(&String) -> &str> = Box::new(|s| &s[0..2]);
+        let m = SelfMonadMut::new(String::from("hello"), c);
+        assert_eq!("he", &*m);
+    }
+
+    #[test]
+    fn mut_closure_as_mut() {
+        let c: Box<dyn FnMut(&mut String) -> &mut str> = Box::new(|s| s[0..2].borrow_mut());
+        let mut m = SelfMonadMut::new_mut(String::from("hello"), c);
+        assert_eq!("he", m.as_mut());
     }
 
     #[test]
@@ -294,4 +2445,334 @@ mod test_mut {
         let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
         assert_eq!("he", m.as_ref());
     }
+
+    #[test]
+    fn mut_debug() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("SelfMonadMut(\"he\")", format!("{:?}", m));
+        assert_eq!("SelfMonadMut(\"he\")", format!("{:?}", m));
+    }
+
+    #[test]
+    fn mut_debug_inside_borrow_mut() {
+        let cell = RefCell::new(SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]));
+        let guard = cell.borrow_mut();
+        assert_eq!("SelfMonadMut([2, 3])", format!("{:?}", *guard));
+        assert_eq!("SelfMonadMut([2,3,],)", format!("{:#?}", *guard).replace(char::is_whitespace, ""));
+    }
+
+    #[test]
+    fn mut_debug_inside_projection() {
+        use std::cell::Cell;
+
+        let probe: Cell<Option<&dyn Fn() -> String>> = Cell::new(None);
+        let seen = Cell::new(None);
+        let m = SelfMonadMut::new(String::from("hello"), |s| {
+            // Runs while `as_ref` holds the borrow of the projection
+            seen.set(probe.get().map(|probe| probe()));
+            &s[0..2]
+        });
+        let format = || format!("{:?}", m);
+        probe.set(Some(&format));
+        assert_eq!("he", &*m);
+        assert_eq!(Some(String::from("SelfMonadMut(<borrowed>)")), seen.take());
+    }
+
+    #[test]
+    fn mut_display() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.to_string());
+        assert_eq!("he", m.to_string());
+    }
+
+    #[test]
+    fn mut_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SelfMonadMut<String, str, fn(&String) -> &str>>();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn mut_box_projection() {
+        let mut calls = 0;
+        let monads: Vec<BoxedSelfMonadMut<String, str>> = vec![
+            BoxedSelfMonadMut::box_projection(String::from("hello"), |s| &s[3..]),
+            BoxedSelfMonadMut::box_projection(String::from("world"), move |s| {
+                calls += 1;
+                &s[..calls]
+            })
+        ];
+        assert_eq!("lo", &*monads[0]);
+        assert_eq!("w", &*monads[1]);
+        assert_eq!("wo", &*monads[1]);
+    }
+
+    #[test]
+    fn mut_default() {
+        #[derive(Default)]
+        struct Unset;
+
+        let m: SelfMonadMut<Vec<i32>, [i32], Unset> = Default::default();
+        assert!(m.owner().is_empty());
+        let m = m.with_func(|v| &v[..]);
+        assert_eq!([0; 0], *m);
+    }
+
+    #[test]
+    fn mut_ptr() {
+        let m1 = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        let m2 = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        assert_ne!(m1.owner_ptr(), m2.owner_ptr());
+        assert_ne!(m1.value_ptr(), m2.value_ptr());
+        assert_eq!(m1.value_ptr(), m1.value_ptr());
+    }
+
+    #[test]
+    fn mut_borrowed_owner() {
+        let text = String::from("hello world");
+        let mut m = SelfMonadMut::new(text.as_str(), |s| &s[..5]);
+        assert_eq!("hello", &*m);
+        *m.owner_mut() = "goodbye";
+        assert_eq!("goodb", &*m);
+
+        let mut bytes = [1u8, 2, 3];
+        let mut m = SelfMonadMut::new_mut(&mut bytes[..], |b| &mut b[..1]);
+        m.as_mut()[0] = 9;
+        m.owner_into();
+        assert_eq!([9, 2, 3], bytes);
+    }
+
+    #[test]
+    fn mut_cmp_raw() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[1..3]);
+        assert!(m < "fa");
+        assert!(m > "ea");
+        assert!(m == "el");
+        assert!(m == *"el");
+        assert!(*"el" == m);
+        assert!(*"ea" < m);
+
+        let m = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        assert!(m == [2, 3][..]);
+        assert!([2, 3][..] == m);
+        assert!(m < [2, 4][..]);
+        assert!([3][..] > m);
+    }
+
+    #[test]
+    fn mut_extend() {
+        let mut m = SelfMonadMut::new_mut((String::from("numbers"), vec![0]), |t| &mut t.1);
+        m.extend([1, 2, 3]);
+        m.extend(vec![4]);
+        assert_eq!(vec![0, 1, 2, 3, 4], m.owner().1);
+    }
+
+    #[test]
+    fn mut_with_value() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[1..]);
+        assert_eq!(4, m.with_value(|v| v.len()));
+
+        let mut m = SelfMonadMut::new_mut(String::from("hello"), |s| s);
+        m.with_value_mut(|s| s.push_str(" world"));
+        assert_eq!("hello world", m.owner());
+    }
+
+    #[test]
+    fn mut_map_mut() {
+        let mut m = SelfMonadMut::new_mut(String::from("hello"), |s| s.as_mut_str())
+            .map_mut(|s| &mut s[1..3]);
+        m.as_mut().make_ascii_uppercase();
+        assert_eq!("hELlo", m.owner());
+
+        let mut m = SelfMonadMut::new_mut((String::from("bytes"), vec![1u8, 2, 3, 4]), |t| &mut t.1)
+            .map_mut(|v| v.as_mut_slice())
+            .map_mut(|b| &mut b[2..]);
+        m.as_mut().fill(0);
+        assert_eq!(vec![1, 2, 0, 0], m.owner().1);
+    }
+
+    #[test]
+    fn mut_fmt_write() {
+        use std::fmt::Write;
+
+        let mut m = SelfMonadMut::new_mut((1, String::from("log:")), |t| &mut t.1);
+        write!(m, " {} + {}", 1, 2).unwrap();
+        m.write_char('!').unwrap();
+        assert_eq!("log: 1 + 2!", m.owner().1);
+    }
+
+    #[test]
+    fn mut_into_parts() {
+        let mut calls = 0;
+        let m = SelfMonadMut::new(String::from("hello"), move |s| {
+            calls += 1;
+            &s[0..calls]
+        });
+        assert_eq!("h", &*m);
+        let (owner, func) = m.into_parts();
+        assert_eq!("hello", owner);
+        let m = SelfMonadMut::new(String::from("world"), func);
+        assert_eq!("wo", &*m);
+    }
+
+    #[test]
+    fn mut_clone_projection() {
+        let mut calls = 0;
+        let first = SelfMonadMut::new(String::from("hello"), move |s| {
+            calls += 1;
+            &s[0..calls]
+        });
+        assert_eq!("h", &*first);
+        let second = SelfMonadMut::new(String::from("world"), first.clone_projection());
+        assert_eq!("wo", &*second);
+        assert_eq!("he", &*first);
+    }
+
+    #[test]
+    fn mut_into_immutable() {
+        let m = SelfMonadMut::new(String::from("hello"), (|s| &s[1..3]) as fn(&String) -> &str);
+        assert_eq!("el", &*m);
+        let m = m.into_immutable();
+        for _ in 0..3 {
+            assert_eq!("el", &*m);
+        }
+        assert_eq!("hello", m.owner());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn mut_retain() {
+        let mut m = SelfMonadMut::new_mut((String::from("numbers"), vec![1, 2, 3, 4, 5, 6]), |t| &mut t.1);
+        m.retain(|n| n % 2 == 0);
+        assert_eq!(vec![2, 4, 6], m.owner().1);
+        assert_eq!("numbers", m.owner().0);
+    }
+
+    #[test]
+    fn mut_debug_borrow_depth() {
+        use std::cell::Cell;
+
+        let probe: Cell<Option<&dyn Fn() -> (usize, bool)>> = Cell::new(None);
+        let seen = Cell::new(None);
+        let m = SelfMonadMut::new(String::from("hello"), |s| {
+            seen.set(probe.get().map(|probe| probe()));
+            &s[0..2]
+        });
+        assert_eq!(0, m.debug_borrow_depth());
+
+        // The inner `as_ref` runs inside the outer projection and panics on the borrowed projection
+        let check = || {
+            let nested = panic::catch_unwind(AssertUnwindSafe(|| m.as_ref().len()));
+            (m.debug_borrow_depth(), nested.is_err())
+        };
+        probe.set(Some(&check));
+        assert_eq!("he", m.as_ref());
+        let running = if cfg!(debug_assertions) { 1 } else { 0 };
+        assert_eq!(Some((running, true)), seen.get());
+
+        probe.set(None);
+        let (first, second) = (m.as_ref(), m.as_ref());
+        assert_eq!(("he", "he"), (first, second));
+        assert_eq!(0, m.debug_borrow_depth());
+    }
+
+    #[test]
+    fn mut_swap_value() {
+        let mut m = SelfMonadMut::new_mut((String::from("counter"), 1), |t| &mut t.1);
+        assert_eq!(1, m.swap_value(5));
+        assert_eq!(5, m.owner().1);
+    }
+
+    #[test]
+    fn mut_value() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.value());
+    }
+
+    #[test]
+    fn mut_owner_and_value() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
+        let (owner, value) = m.owner_and_value();
+        assert_eq!("hello", owner);
+        assert_eq!("he", value);
+    }
+
+    #[test]
+    fn mut_with_func() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        let m = m.with_func(|s| &s[1..3]);
+        assert_eq!("el", &*m);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn mut_to_owned_value() {
+        let m = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        assert_eq!(vec![2, 3], m.to_owned_value());
+    }
+
+    #[test]
+    fn mut_replace_owner() {
+        let mut m = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        assert_eq!(vec![1, 2, 3], m.replace_owner(vec![4, 5, 6]));
+        assert_eq!([5, 6], *m);
+    }
+
+    #[test]
+    fn mut_index() {
+        let m = SelfMonadMut::new(String::from("hello"), |s| &s[1..]);
+        assert_eq!("ll", &m[1..3]);
+    }
+
+    #[test]
+    fn mut_value_mut() {
+        let mut m = SelfMonadMut::new_mut(vec![1, 2, 3], |v| &mut v[1..]);
+        m.value_mut()[0] = 5;
+        assert_eq!(&vec![1, 5, 3], m.owner());
+    }
+
+    #[test]
+    fn mut_clone_state() {
+        let mut len = 0;
+        let m1 = SelfMonadMut::new(vec![1, 2, 3, 4], move |v| {
+            len += 1;
+            &v[..len]
+        });
+        assert_eq!([1], *m1);
+        assert_eq!([1, 2], *m1);
+
+        let m2 = m1.clone();
+        assert_eq!([1, 2, 3], *m2);
+        assert_eq!([1, 2, 3, 4], *m2);
+        assert_eq!([1, 2, 3], *m1);
+    }
+
+    #[test]
+    fn mut_from_tuple() {
+        let m: SelfMonadMut<_, _, _> = (vec![1, 2, 3], (|v| &v[1..]) as fn(&Vec<i32>) -> &[i32]).into();
+        assert_eq!([2, 3], *m);
+    }
+
+    #[test]
+    fn mut_borrow() {
+        let m = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        let value: &[i32] = Borrow::borrow(&m);
+        assert_eq!([2, 3], value);
+    }
+
+    #[test]
+    fn mut_hash() {
+        fn hash<T: ?Sized + Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let m1 = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        let m2 = SelfMonadMut::new(vec![2, 3], |v| &v[..]);
+        assert_eq!(m1, m2);
+        assert_eq!(hash(&m1), hash(&m2));
+        assert_eq!(hash(&[2, 3][..]), hash(&m1));
+    }
 }
@@ -0,0 +1,74 @@
+use core::marker::PhantomData;
+
+use crate::SelfMonadOwner;
+
+/// Projects the owner with a closure that may find nothing to project.
+pub struct SelfMonadOpt<O, V: ?Sized, F> {
+    owner: O,
+    func: F,
+    phantom: PhantomData<fn() -> V>
+}
+
+/// A `SelfMonadOpt` used for defensive projections that fall back to a default through `get_or`.
+pub type SelfMonadSafe<O, V, F> = SelfMonadOpt<O, V, F>;
+
+impl<O, V: ?Sized, F: Fn(&O) -> Option<&V>> SelfMonadOpt<O, V, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadOpt {
+            owner,
+            func,
+            phantom: PhantomData
+        }
+    }
+
+    /// Runs the projection and returns its result.
+    pub fn get(&self) -> Option<&V> {
+        (self.func)(&self.owner)
+    }
+
+    /// Runs the projection and returns its result, or `default` if there is nothing to project.
+    pub fn get_or<'a>(&'a self, default: &'a V) -> &'a V {
+        self.get().unwrap_or(default)
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOwner<O> for SelfMonadOpt<O, V, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadOpt, SelfMonadOwner, SelfMonadSafe};
+
+    #[test]
+    fn opt_get() {
+        let mut m = SelfMonadOpt::new(vec![1, 2, 3], |v| v.get(2));
+        assert_eq!(Some(&3), m.get());
+        m.owner_mut().truncate(2);
+        assert_eq!(None, m.get());
+    }
+
+    #[test]
+    fn opt_get_or_in_bounds() {
+        let m = SelfMonadSafe::new(String::from("hello"), |s| s.get(0..2));
+        assert_eq!("he", m.get_or(""));
+    }
+
+    #[test]
+    fn opt_get_or_out_of_bounds() {
+        let mut m = SelfMonadSafe::new(String::from("hello"), |s| s.get(3..6));
+        assert_eq!("", m.get_or(""));
+        m.owner_mut().push('!');
+        assert_eq!("lo!", m.get_or(""));
+    }
+}
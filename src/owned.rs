@@ -0,0 +1,80 @@
+use core::marker::PhantomData;
+
+use crate::SelfMonadOwner;
+
+/// Computes a value from the owner instead of borrowing one.
+pub struct SelfMonadOwned<O, V, F> {
+    owner: O,
+    func: F,
+    phantom: PhantomData<fn() -> V>
+}
+
+impl<O, V, F: Fn(&O) -> V> SelfMonadOwned<O, V, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadOwned {
+            owner,
+            func,
+            phantom: PhantomData
+        }
+    }
+
+    /// Runs the projection and returns its result.
+    pub fn get(&self) -> V {
+        (self.func)(&self.owner)
+    }
+}
+
+/// Runs the projection once and iterates its result.
+impl<O, V: IntoIterator, F: Fn(&O) -> V> IntoIterator for SelfMonadOwned<O, V, F> {
+    type Item = V::Item;
+    type IntoIter = V::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.get().into_iter()
+    }
+}
+
+impl<O, V, F> SelfMonadOwner<O> for SelfMonadOwned<O, V, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadOwned, SelfMonadOwner};
+
+    #[test]
+    fn owned_get() {
+        let mut m = SelfMonadOwned::new(String::from("hello"), |s| s.len());
+        assert_eq!(5, m.get());
+        m.owner_mut().push('!');
+        assert_eq!(6, m.get());
+    }
+
+    #[test]
+    fn owned_into_iter() {
+        let m = SelfMonadOwned::new(String::from("1 2 3"), |s| {
+            s.split(' ').map(|n| n.parse().unwrap()).collect::<Vec<i32>>()
+        });
+        let mut sum = 0;
+        for n in m {
+            sum += n;
+        }
+        assert_eq!(6, sum);
+    }
+
+    #[test]
+    fn owned_parse() {
+        let m = SelfMonadOwned::new(String::from("42 apples"), |s| s.split(' ').next().unwrap().parse::<i32>());
+        assert_eq!(Ok(42), m.get());
+    }
+}
@@ -0,0 +1,98 @@
+use core::cell::OnceCell;
+
+use crate::SelfMonadOwner;
+
+/// Like `SelfMonadOwned`, but computes the value only on first access and returns the stored one
+/// until `clear` is called.
+pub struct SelfMonadOwnedCached<O, V, F> {
+    owner: O,
+    func: F,
+    value: OnceCell<V>
+}
+
+impl<O, V, F: Fn(&O) -> V> SelfMonadOwnedCached<O, V, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadOwnedCached {
+            owner,
+            func,
+            value: OnceCell::new()
+        }
+    }
+
+    /// Returns the stored value, running the projection if there is none.
+    pub fn get(&self) -> &V {
+        self.value.get_or_init(|| (self.func)(&self.owner))
+    }
+}
+
+impl<O, V, F> SelfMonadOwnedCached<O, V, F> {
+    /// Drops the stored value, so the next access runs the projection again.
+    pub fn clear(&mut self) {
+        self.value.take();
+    }
+}
+
+impl<O, V, F> SelfMonadOwner<O> for SelfMonadOwnedCached<O, V, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    /// Drops the stored value, since the owner may be changed through the returned reference.
+    fn owner_mut(&mut self) -> &mut O {
+        self.clear();
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadOwnedCached, SelfMonadOwner};
+    use std::cell::Cell;
+
+    #[test]
+    fn owned_cached_runs_once() {
+        let calls = Cell::new(0);
+        let mut m = SelfMonadOwnedCached::new(String::from("hello world"), |s| {
+            calls.set(calls.get() + 1);
+            s.split(' ').map(String::from).collect::<Vec<_>>()
+        });
+        assert_eq!(2, m.get().len());
+        assert_eq!("world", m.get()[1]);
+        assert_eq!(1, calls.get());
+
+        m.clear();
+        assert_eq!("hello", m.get()[0]);
+        assert_eq!(2, calls.get());
+    }
+
+    #[test]
+    fn owned_cached_owner_mut() {
+        let mut m = SelfMonadOwnedCached::new(vec![1, 2, 3], |v| v.iter().sum::<i32>());
+        assert_eq!(6, *m.get());
+        m.owner_mut().push(4);
+        assert_eq!(10, *m.get());
+    }
+
+    #[test]
+    fn owned_cached_update_owner() {
+        let mut m = SelfMonadOwnedCached::new(String::from("hello"), |s| s.len());
+        assert_eq!(5, *m.get());
+        m.update_owner(|s| s + " world");
+        assert_eq!(11, *m.get());
+    }
+
+    #[test]
+    fn owned_cached_owner_mut_then() {
+        let mut m = SelfMonadOwnedCached::new(vec![1, 2, 3], |v| v.len());
+        assert_eq!(3, *m.get());
+        assert_eq!(4, m.owner_mut_then(|v| {
+            v.push(4);
+            v.len()
+        }));
+        assert_eq!(4, *m.get());
+    }
+}
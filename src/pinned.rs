@@ -0,0 +1,195 @@
+use core::cell::Cell;
+use core::hash::{Hash, Hasher};
+use core::marker::{PhantomData, PhantomPinned};
+use core::ops::Deref;
+use core::pin::Pin;
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use crate::ProjectionError;
+
+/// The cached projection of an owner that is pinned together with the cache.
+pub(crate) struct PinnedCache<V: ?Sized>(Cell<Option<*const V>>);
+
+impl<V: ?Sized> PinnedCache<V> {
+    pub(crate) fn new() -> Self {
+        PinnedCache(Cell::new(None))
+    }
+
+    /// Returns the cached projection, running `project` if there is none.
+    ///
+    /// # Safety
+    ///
+    /// Everything the result of `project` borrows from must stay in place and unchanged until the
+    /// cache is cleared or dropped, which holds for an owner pinned in the same struct as the cache
+    /// and only handed out mutably after `clear`.
+    pub(crate) unsafe fn get_or_init<'a, P: FnOnce() -> &'a V>(&'a self, project: P) -> &'a V {
+        if let Some(value) = self.0.get() {
+            return &*value;
+        }
+        let value = project();
+        self.0.set(Some(value));
+        value
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.0.set(None);
+    }
+}
+
+/// Projects an owner that is pinned on construction, so the cached projection never has to follow
+/// the owner to a new address.
+///
+/// Like `SelfMonadOnce`, the projection runs on first access, but its result is cached for every later
+/// access.
+pub struct SelfMonadPinned<O, V: ?Sized, F> {
+    owner: O,
+    func: Cell<Option<F>>,
+    value: PinnedCache<V>,
+    phantom: PhantomData<fn() -> V>,
+    _pinned: PhantomPinned
+}
+
+impl<O, V: ?Sized, F: FnOnce(&O) -> &V> SelfMonadPinned<O, V, F> {
+    pub fn new(owner: O, func: F) -> Pin<Box<Self>> {
+        Box::pin(SelfMonadPinned {
+            owner,
+            func: Cell::new(Some(func)),
+            value: PinnedCache::new(),
+            phantom: PhantomData,
+            _pinned: PhantomPinned
+        })
+    }
+
+    /// Runs the projection right away and caches its result, so a failing projection is caught on
+    /// construction instead of on first access. See `ProjectionError` for its limits.
+    #[cfg(feature = "std")]
+    pub fn try_new(owner: O, func: F) -> Result<Pin<Box<Self>>, ProjectionError> {
+        let m = SelfMonadPinned::new(owner, func);
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            m.as_ref().as_ref();
+        })).map_err(ProjectionError::from_panic)?;
+        Ok(m)
+    }
+
+    /// Returns the projected value, running the projection on first access.
+    #[inline]
+    pub fn as_ref(self: Pin<&Self>) -> &V {
+        self.get_ref().value()
+    }
+
+    fn value(&self) -> &V {
+        // Every instance lives behind the `Pin` returned by `new`, and the owner is never handed out
+        // mutably
+        unsafe { self.value.get_or_init(|| (self.func.take().unwrap())(&self.owner)) }
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadPinned<O, V, F> {
+    pub fn owner(&self) -> &O {
+        &self.owner
+    }
+}
+
+impl<O, V: ?Sized, F: FnOnce(&O) -> &V> Deref for SelfMonadPinned<O, V, F> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.value()
+    }
+}
+
+/// Compares the projected values, running the projections if they have not run yet. Later
+/// comparisons reuse the cached projections.
+impl<O, V, F, O2, V2, F2> PartialEq<SelfMonadPinned<O2, V2, F2>> for SelfMonadPinned<O, V, F>
+where
+    V: ?Sized + PartialEq<V2>,
+    V2: ?Sized,
+    F: FnOnce(&O) -> &V,
+    F2: FnOnce(&O2) -> &V2
+{
+    fn eq(&self, other: &SelfMonadPinned<O2, V2, F2>) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl<O, V: ?Sized + Eq, F: FnOnce(&O) -> &V> Eq for SelfMonadPinned<O, V, F> {}
+
+/// Hashes the projected value, running the projection if it has not run yet. Later hashes reuse the
+/// cached projection.
+impl<O, V: ?Sized + Hash, F: FnOnce(&O) -> &V> Hash for SelfMonadPinned<O, V, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SelfMonadPinned;
+    use std::collections::HashSet;
+    use std::ptr;
+
+    #[test]
+    fn pinned_as_ref() {
+        let m = SelfMonadPinned::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", m.as_ref().as_ref());
+        assert_eq!("he", &**m);
+    }
+
+    #[test]
+    fn pinned_eq() {
+        let m1 = SelfMonadPinned::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = SelfMonadPinned::new(vec![String::from("he")], |v| v[0].as_str());
+        assert!(m1 == m2);
+        assert!(m1 == m2);
+        assert!(m1 != SelfMonadPinned::new(String::from("hello"), |s| &s[1..]));
+    }
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn pinned_hash_set() {
+        let prefix = (|s| &s[0..2]) as fn(&String) -> &str;
+        let mut set = HashSet::new();
+        assert!(set.insert(SelfMonadPinned::new(String::from("hello"), prefix)));
+        assert!(!set.insert(SelfMonadPinned::new(String::from("help"), prefix)));
+        assert!(set.contains(&SelfMonadPinned::new(String::from("hey"), prefix)));
+
+        let m = set.iter().next().unwrap();
+        assert_eq!("he", &***m);
+        assert_eq!("he", &***m);
+        assert_eq!("hello", m.owner());
+    }
+
+    #[test]
+    fn pinned_move() {
+        let m = SelfMonadPinned::new([1, 2, 3, 4], |a| &a[1..3]);
+        let cached: *const [i32] = m.as_ref().as_ref();
+
+        let mut moved = vec![m];
+        let m = moved.pop().unwrap();
+        assert!(ptr::eq(cached, m.as_ref().as_ref()));
+        assert_eq!([2, 3], **m);
+        assert_eq!(&m.owner()[1], &m[0]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn pinned_try_new() {
+        let m = SelfMonadPinned::try_new([1, 2, 3, 4], |a| &a[1..3]).unwrap();
+        assert_eq!([2, 3], **m);
+        let mut moved = vec![m];
+        let m = moved.pop().unwrap();
+        assert_eq!([2, 3], **m);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn pinned_try_new_panic() {
+        match SelfMonadPinned::try_new(String::from("hello"), |s| &s[0..10]) {
+            Ok(_) => panic!("projection should have failed"),
+            Err(e) => assert!(e.message().contains("out of bounds"))
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! The monad types and their traits, for a single glob import.
+//!
+//! ```
+//! use self_monad::prelude::*;
+//!
+//! let mut m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+//! assert_eq!("he", &*m);
+//! m.owner_mut().insert(0, 'w');
+//! assert_eq!("wh", &*m);
+//! ```
+
+pub use crate::{AnySelfMonad, AsRefOwner, ProjectsTo, SelfMonad, SelfMonadMut, SelfMonadOnce, SelfMonadOwner};
@@ -0,0 +1,92 @@
+use crate::SelfMonadOwner;
+
+/// A nominal projection, for when a closure's higher-ranked signature fails to infer, e.g. when it
+/// is stored in a variable before being passed to `SelfMonad::new`.
+pub trait Projection<O: ?Sized> {
+    type Output<'a>: ?Sized
+    where
+        O: 'a;
+
+    fn project<'a>(&self, o: &'a O) -> &'a Self::Output<'a>;
+}
+
+/// Projects the owner with a `Projection`, whose output may borrow from the owner.
+pub struct SelfMonadProj<O, P> {
+    owner: O,
+    proj: P
+}
+
+impl<O, P: Projection<O>> SelfMonadProj<O, P> {
+    pub fn new(owner: O, proj: P) -> Self {
+        SelfMonadProj {
+            owner,
+            proj
+        }
+    }
+
+    /// Runs the projection and returns its result, which may borrow from `self`.
+    pub fn get(&self) -> &P::Output<'_> {
+        self.proj.project(&self.owner)
+    }
+}
+
+impl<O, P> SelfMonadOwner<O> for SelfMonadProj<O, P> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Projection, SelfMonadOwner, SelfMonadProj};
+
+    struct Column(usize);
+
+    impl Projection<Vec<Vec<String>>> for Column {
+        type Output<'a> = str;
+
+        fn project<'a>(&self, rows: &'a Vec<Vec<String>>) -> &'a str {
+            rows.iter().map(|row| row[self.0].as_str()).max_by_key(|cell| cell.len()).unwrap_or("")
+        }
+    }
+
+    struct Tail;
+
+    impl Projection<Vec<&'static str>> for Tail {
+        type Output<'a> = [&'a str];
+
+        fn project<'a>(&self, words: &'a Vec<&'static str>) -> &'a [&'a str] {
+            &words[1..]
+        }
+    }
+
+    #[test]
+    fn projection_column() {
+        let rows = vec![
+            vec![String::from("id"), String::from("name")],
+            vec![String::from("1"), String::from("alice")]
+        ];
+        let mut names = SelfMonadProj::new(rows.clone(), Column(1));
+        let ids = SelfMonadProj::new(rows, Column(0));
+        assert_eq!("alice", names.get());
+        assert_eq!("id", ids.get());
+        names.owner_mut().push(vec![String::from("2"), String::from("charlotte")]);
+        assert_eq!("charlotte", names.get());
+    }
+
+    #[test]
+    fn projection_borrowing_output() {
+        let m = SelfMonadProj::new(vec!["a", "b", "c"], Tail);
+        let tail: &[&str] = m.get();
+        assert_eq!(["b", "c"], tail);
+        assert_eq!(3, m.owner().len());
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{SelfMonad, SelfMonadMut, SelfMonadOnce};
+
+/// Names the projected type of a monad, so generic code can bound on it, e.g.
+/// `fn f<M: ProjectsTo<Value = str>>(m: &M)`.
+pub trait ProjectsTo {
+    type Value: ?Sized;
+
+    fn project(&self) -> &Self::Value;
+}
+
+impl<O, V: ?Sized, F: FnOnce(&O) -> &V> ProjectsTo for SelfMonadOnce<O, V, F> {
+    type Value = V;
+
+    fn project(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> ProjectsTo for SelfMonad<O, V, F> {
+    type Value = V;
+
+    fn project(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> ProjectsTo for SelfMonadMut<O, V, F> {
+    type Value = V;
+
+    fn project(&self) -> &V {
+        self.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ProjectsTo, SelfMonad, SelfMonadMut, SelfMonadOnce};
+
+    fn shout<M: ProjectsTo<Value = str>>(m: &M) -> String {
+        m.project().to_uppercase()
+    }
+
+    #[test]
+    fn projects_to_str() {
+        assert_eq!("HE", shout(&SelfMonad::new(String::from("hello"), |s| &s[0..2])));
+        assert_eq!("WORLD", shout(&SelfMonadMut::new((1, String::from("world")), |t| t.1.as_str())));
+        assert_eq!("ONCE", shout(&SelfMonadOnce::new(vec![String::from("once")], |v| v[0].as_str())));
+    }
+}
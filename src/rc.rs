@@ -0,0 +1,109 @@
+use core::marker::PhantomData;
+use core::ops::Deref;
+use alloc::rc::Rc;
+
+use crate::SelfMonadOwner;
+
+/// Projects an owner shared through an `Rc`, so clones are cheap without atomic reference counting.
+pub struct SelfMonadRc<O, V: ?Sized, F> {
+    owner: Rc<O>,
+    func: Rc<F>,
+    phantom: PhantomData<fn() -> V>
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> SelfMonadRc<O, V, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadRc {
+            owner: Rc::new(owner),
+            func: Rc::new(func),
+            phantom: PhantomData
+        }
+    }
+
+    /// Returns the address of the shared owner, the same for all clones.
+    pub fn owner_ptr(&self) -> *const O {
+        Rc::as_ptr(&self.owner)
+    }
+
+    /// Runs the projection and returns the address of the projected value, for identity checks.
+    pub fn value_ptr(&self) -> *const V {
+        self.as_ref()
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> Deref for SelfMonadRc<O, V, F> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized, F: Fn(&O) -> &V> AsRef<V> for SelfMonadRc<O, V, F> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        (self.func)(&self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F> Clone for SelfMonadRc<O, V, F> {
+    fn clone(&self) -> Self {
+        SelfMonadRc {
+            owner: Rc::clone(&self.owner),
+            func: Rc::clone(&self.func),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOwner<Rc<O>> for SelfMonadRc<O, V, F> {
+    fn owner(&self) -> &Rc<O> {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut Rc<O> {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> Rc<O> {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadOwner, SelfMonadRc};
+    use std::rc::Rc;
+
+    #[test]
+    fn rc_deref() {
+        let m = SelfMonadRc::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        assert_eq!("he", m.as_ref());
+    }
+
+    #[test]
+    fn rc_ptr() {
+        let m1 = SelfMonadRc::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = m1.clone();
+        assert_eq!(m1.owner_ptr(), m2.owner_ptr());
+        assert_eq!(m1.value_ptr(), m2.value_ptr());
+
+        let m3 = SelfMonadRc::new(String::from("hello"), |s| &s[0..2]);
+        assert_ne!(m1.owner_ptr(), m3.owner_ptr());
+        assert_ne!(m1.value_ptr(), m3.value_ptr());
+    }
+
+    #[test]
+    fn rc_clone_shares_owner() {
+        let m1 = SelfMonadRc::new(String::from("hello"), |s| &s[0..2]);
+        let m2 = m1.clone();
+        assert!(Rc::ptr_eq(m1.owner(), m2.owner()));
+        assert_eq!(2, Rc::strong_count(m1.owner()));
+        assert_eq!("he", &*m2);
+
+        drop(m2);
+        assert_eq!(1, Rc::strong_count(m1.owner()));
+    }
+}
@@ -0,0 +1,270 @@
+use core::fmt;
+use core::ops::Deref;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer};
+use serde::{Serialize, Serializer};
+
+use crate::{SelfMonad, SelfMonadMut, SelfMonadOwner};
+
+/// Serializes the projected value only, the owner and the projection are not serialized. Use
+/// `SelfMonadNamed` to serialize a monad so that it can be deserialized again.
+impl<O, V: ?Sized + Serialize, F: Fn(&O) -> &V> Serialize for SelfMonad<O, V, F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+/// Serializes the projected value only, the owner and the projection are not serialized.
+impl<O, V: ?Sized + Serialize, F: FnMut(&O) -> &V> Serialize for SelfMonadMut<O, V, F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// A projection known by name, so a monad using it can be serialized with its owner and rebuilt
+/// from a `ProjectionRegistry`.
+pub trait SerdeProjection<O, V: ?Sized> {
+    const NAME: &'static str;
+
+    fn project(owner: &O) -> &V;
+}
+
+/// The name and function of a `SerdeProjection`, stored as the projection of a `SelfMonadNamed`.
+pub struct NamedProjection<O, V: ?Sized> {
+    name: &'static str,
+    func: fn(&O) -> &V
+}
+
+impl<O, V: ?Sized> NamedProjection<O, V> {
+    pub fn of<P: SerdeProjection<O, V>>() -> Self {
+        NamedProjection {
+            name: P::NAME,
+            func: P::project
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<O, V: ?Sized> Clone for NamedProjection<O, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O, V: ?Sized> Copy for NamedProjection<O, V> {}
+
+impl<O, V: ?Sized> fmt::Debug for NamedProjection<O, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NamedProjection").field(&self.name).finish()
+    }
+}
+
+/// Projects the owner with a `SerdeProjection`, so the monad can be serialized along with its owner
+/// and rebuilt from a `ProjectionRegistry`.
+///
+/// Unlike `SelfMonad`, which serializes only its projected value, this serializes the name of the
+/// projection and the owner as a pair.
+pub struct SelfMonadNamed<O, V: ?Sized> {
+    owner: O,
+    proj: NamedProjection<O, V>
+}
+
+impl<O, V: ?Sized> SelfMonadNamed<O, V> {
+    pub fn new<P: SerdeProjection<O, V>>(owner: O) -> Self {
+        SelfMonadNamed {
+            owner,
+            proj: NamedProjection::of::<P>()
+        }
+    }
+
+    /// The name of the projection.
+    pub fn name(&self) -> &'static str {
+        self.proj.name
+    }
+
+    /// Turns this into a plain `SelfMonad` projecting with the same function, which serializes
+    /// only its projected value.
+    pub fn into_monad(self) -> SelfMonad<O, V, fn(&O) -> &V> {
+        SelfMonad::new(self.owner, self.proj.func)
+    }
+}
+
+impl<O, V: ?Sized> Deref for SelfMonadNamed<O, V> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized> AsRef<V> for SelfMonadNamed<O, V> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        (self.proj.func)(&self.owner)
+    }
+}
+
+impl<O, V: ?Sized> SelfMonadOwner<O> for SelfMonadNamed<O, V> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+/// Serializes the name of the projection and the owner as a pair.
+impl<O: Serialize, V: ?Sized> Serialize for SelfMonadNamed<O, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.proj.name, &self.owner).serialize(serializer)
+    }
+}
+
+/// The projections a serialized monad may name, used as a `DeserializeSeed` to rebuild it.
+#[cfg(feature = "alloc")]
+pub struct ProjectionRegistry<O, V: ?Sized> {
+    projections: Vec<NamedProjection<O, V>>
+}
+
+#[cfg(feature = "alloc")]
+impl<O, V: ?Sized> ProjectionRegistry<O, V> {
+    pub fn new() -> Self {
+        ProjectionRegistry { projections: Vec::new() }
+    }
+
+    /// Adds `P` to the registry. A later projection with the same name shadows an earlier one.
+    pub fn register<P: SerdeProjection<O, V>>(&mut self) -> &mut Self {
+        self.projections.push(NamedProjection::of::<P>());
+        self
+    }
+
+    /// Looks a projection up by name.
+    pub fn get(&self, name: &str) -> Option<NamedProjection<O, V>> {
+        self.projections.iter().rev().find(|p| p.name == name).copied()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<O, V: ?Sized> Default for ProjectionRegistry<O, V> {
+    fn default() -> Self {
+        ProjectionRegistry::new()
+    }
+}
+
+/// Reads the pair written by `Serialize`, failing if the projection isn't registered.
+#[cfg(feature = "alloc")]
+impl<'de, O: Deserialize<'de>, V: ?Sized> DeserializeSeed<'de> for &ProjectionRegistry<O, V> {
+    type Value = SelfMonadNamed<O, V>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let (name, owner) = <(String, O)>::deserialize(deserializer)?;
+        let proj = self
+            .get(&name)
+            .ok_or_else(|| de::Error::custom(format_args!("unknown projection `{}`", name)))?;
+        Ok(SelfMonadNamed { owner, proj })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonad, SelfMonadMut, SelfMonadNamed, SerdeProjection};
+    #[cfg(feature = "alloc")]
+    use crate::{ProjectionRegistry, SelfMonadOwner};
+    #[cfg(feature = "alloc")]
+    use serde::de::DeserializeSeed;
+    use serde::Serialize;
+
+    #[test]
+    fn serialize() {
+        let m = SelfMonad::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("\"he\"", serde_json::to_string(&m).unwrap());
+    }
+
+    #[test]
+    fn serialize_field() {
+        #[derive(Serialize)]
+        struct Record {
+            name: SelfMonad<String, str, fn(&String) -> &str>
+        }
+
+        let record = Record { name: SelfMonad::new(String::from("hello"), |s| &s[0..2]) };
+        assert_eq!("{\"name\":\"he\"}", serde_json::to_string(&record).unwrap());
+    }
+
+    #[test]
+    fn mut_serialize() {
+        let m = SelfMonadMut::new(vec![1, 2, 3], |v| &v[1..]);
+        assert_eq!("[2,3]", serde_json::to_string(&m).unwrap());
+    }
+
+    struct Head;
+
+    impl SerdeProjection<String, str> for Head {
+        const NAME: &'static str = "head";
+
+        fn project(owner: &String) -> &str {
+            &owner[0..2]
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    struct Tail;
+
+    #[cfg(feature = "alloc")]
+    impl SerdeProjection<String, str> for Tail {
+        const NAME: &'static str = "tail";
+
+        fn project(owner: &String) -> &str {
+            &owner[2..]
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn named_roundtrip() {
+        let mut registry = ProjectionRegistry::new();
+        registry.register::<Head>().register::<Tail>();
+
+        let m = SelfMonadNamed::new::<Tail>(String::from("hello"));
+        assert_eq!("llo", &*m);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!("[\"tail\",\"hello\"]", json);
+
+        let mut m = registry.deserialize(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+        assert_eq!("llo", &*m);
+        assert_eq!("tail", m.name());
+        m.owner_mut().push('!');
+        assert_eq!("llo!", &*m);
+    }
+
+    #[test]
+    fn named_into_monad() {
+        let m = SelfMonadNamed::new::<Head>(String::from("hello")).into_monad();
+        assert_eq!("he", &*m);
+        assert_eq!("\"he\"", serde_json::to_string(&m).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn named_unknown_projection() {
+        let mut registry = ProjectionRegistry::<String, str>::new();
+        registry.register::<Head>();
+        let json = "[\"tail\",\"hello\"]";
+        let result = registry.deserialize(&mut serde_json::Deserializer::from_str(json));
+        assert!(result.err().unwrap().to_string().contains("unknown projection `tail`"));
+    }
+}
@@ -0,0 +1,75 @@
+use core::marker::PhantomData;
+
+use crate::SelfMonadOwner;
+
+/// Projects the owner into two disjoint mutable parts at once.
+pub struct SelfMonadSplit<O, A: ?Sized, B: ?Sized, F> {
+    owner: O,
+    func: F,
+    first: PhantomData<fn() -> A>,
+    second: PhantomData<fn() -> B>
+}
+
+impl<O, A: ?Sized, B: ?Sized, F: FnMut(&mut O) -> (&mut A, &mut B)> SelfMonadSplit<O, A, B, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadSplit {
+            owner,
+            func,
+            first: PhantomData,
+            second: PhantomData
+        }
+    }
+
+    /// Runs the projection and returns both parts.
+    pub fn parts_mut(&mut self) -> (&mut A, &mut B) {
+        (self.func)(&mut self.owner)
+    }
+}
+
+impl<O, A: ?Sized, B: ?Sized, F> SelfMonadOwner<O> for SelfMonadSplit<O, A, B, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadOwner, SelfMonadSplit};
+
+    struct Account {
+        name: String,
+        history: Vec<i32>,
+        balance: i32
+    }
+
+    #[test]
+    fn split_fields() {
+        let account = Account { name: String::from("savings"), history: vec![], balance: 0 };
+        let mut m = SelfMonadSplit::new(account, |a| (&mut a.history, &mut a.balance));
+
+        for amount in [10, -3, 5] {
+            let (history, balance) = m.parts_mut();
+            history.push(amount);
+            *balance += amount;
+        }
+        assert_eq!(vec![10, -3, 5], m.owner().history);
+        assert_eq!(12, m.owner().balance);
+        assert_eq!("savings", m.owner().name);
+    }
+
+    #[test]
+    fn split_slices() {
+        let mut m = SelfMonadSplit::new(vec![1, 2, 3, 4], |v| v.split_at_mut(2));
+        let (left, right) = m.parts_mut();
+        left.swap_with_slice(right);
+        assert_eq!(&vec![3, 4, 1, 2], m.owner());
+    }
+}
@@ -0,0 +1,88 @@
+use core::cell::{Ref, RefCell};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::{BorrowDepth, SelfMonadMut};
+
+/// A projection paired with state it updates on every run.
+///
+/// Keeping the state next to the closure, instead of in captured `Cell`s, lets the monad hand it out
+/// through `state`.
+pub struct StatefulFunc<S, G> {
+    state: S,
+    func: G
+}
+
+impl<O, V: ?Sized, S, G> SelfMonadMut<O, V, StatefulFunc<S, G>>
+where
+    G: for<'a> FnMut(&mut S, &'a O) -> &'a V
+{
+    /// Builds a monad whose projection receives `state` mutably on every run.
+    pub fn with_state(owner: O, state: S, func: G) -> Self {
+        SelfMonadMut {
+            owner,
+            func: RefCell::new(StatefulFunc { state, func }),
+            depth: BorrowDepth::default(),
+            phantom: PhantomData
+        }
+    }
+
+    /// Borrows the state of the projection.
+    ///
+    /// Returns a `Ref` rather than `&S`, since the next access runs the projection and updates the
+    /// state again. Panics if called from inside the projection itself.
+    pub fn state(&self) -> Ref<'_, S> {
+        Ref::map(self.func.borrow(), |f| &f.state)
+    }
+
+    /// Mutably borrows the state of the projection.
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.func.get_mut().state
+    }
+}
+
+impl<O, V: ?Sized, S, G> Deref for SelfMonadMut<O, V, StatefulFunc<S, G>>
+where
+    G: for<'a> FnMut(&mut S, &'a O) -> &'a V
+{
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+impl<O, V: ?Sized, S, G> AsRef<V> for SelfMonadMut<O, V, StatefulFunc<S, G>>
+where
+    G: for<'a> FnMut(&mut S, &'a O) -> &'a V
+{
+    #[inline]
+    fn as_ref(&self) -> &V {
+        let _depth = self.depth.enter();
+        let mut func = self.func.borrow_mut();
+        let StatefulFunc { state, func } = &mut *func;
+        func(state, &self.owner)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadMut, SelfMonadOwner};
+
+    #[test]
+    fn state_counts_runs() {
+        let mut m = SelfMonadMut::with_state(String::from("hello"), 0, |runs: &mut i32, s: &String| {
+            *runs += 1;
+            &s[0..2]
+        });
+        assert_eq!(0, *m.state());
+        assert_eq!("he", &*m);
+        assert_eq!("he", m.as_ref());
+        assert_eq!(2, *m.state());
+        *m.state_mut() = 10;
+        m.owner_mut().make_ascii_uppercase();
+        assert_eq!("HE", &*m);
+        assert_eq!(11, *m.state());
+    }
+}
@@ -0,0 +1,109 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::{Mutex, PoisonError};
+
+use crate::SelfMonadOwner;
+
+/// Like `SelfMonadMut`, but guards the projection with a `Mutex`, so it can be shared between threads.
+pub struct SelfMonadMutSync<O, V: ?Sized, F> {
+    owner: O,
+    func: Mutex<F>,
+    phantom: PhantomData<fn() -> V>
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> SelfMonadMutSync<O, V, F> {
+    pub fn new(owner: O, func: F) -> Self {
+        SelfMonadMutSync {
+            owner,
+            func: Mutex::new(func),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> SelfMonadMutSync<O, V, F> {
+    pub fn new_mut(owner: O, func: F) -> Self {
+        SelfMonadMutSync {
+            owner,
+            func: Mutex::new(func),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> Deref for SelfMonadMutSync<O, V, F> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        AsRef::as_ref(self)
+    }
+}
+
+/// Locks the projection while it runs. A projection that panicked does not poison later calls.
+impl<O, V: ?Sized, F: FnMut(&O) -> &V> AsRef<V> for SelfMonadMutSync<O, V, F> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        (*self.func.lock().unwrap_or_else(PoisonError::into_inner))(&self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F: FnMut(&mut O) -> &mut V> AsMut<V> for SelfMonadMutSync<O, V, F> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut V {
+        (*self.func.get_mut().unwrap_or_else(PoisonError::into_inner))(&mut self.owner)
+    }
+}
+
+impl<O, V: ?Sized, F> SelfMonadOwner<O> for SelfMonadMutSync<O, V, F> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{SelfMonadMutSync, SelfMonadOwner};
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn sync_deref() {
+        let m = SelfMonadMutSync::new(String::from("hello"), |s| &s[0..2]);
+        assert_eq!("he", &*m);
+        assert_eq!("he", m.as_ref());
+    }
+
+    #[test]
+    fn sync_as_mut() {
+        let mut m = SelfMonadMutSync::new_mut(vec![1, 2, 3], |v| &mut v[1..]);
+        m.as_mut()[0] = 5;
+        assert_eq!(&vec![1, 5, 3], m.owner());
+    }
+
+    #[test]
+    fn sync_threads() {
+        let mut calls = 0;
+        let m = Arc::new(SelfMonadMutSync::new(String::from("hello"), move |s| {
+            calls += 1;
+            &s[0..calls.min(2)]
+        }));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let m = Arc::clone(&m);
+                thread::spawn(move || m.as_ref().len())
+            })
+            .collect();
+        let lengths: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(1, lengths.iter().filter(|&&len| len == 1).count());
+        assert_eq!("he", &**m);
+    }
+}
@@ -0,0 +1,76 @@
+use core::marker::PhantomData;
+
+use crate::SelfMonadOwner;
+
+/// Projects the owner with two closures at once.
+pub struct SelfMonadZip<O, A: ?Sized, B: ?Sized, F, G> {
+    owner: O,
+    first_func: F,
+    second_func: G,
+    first: PhantomData<fn() -> A>,
+    second: PhantomData<fn() -> B>
+}
+
+impl<O, A: ?Sized, B: ?Sized, F: Fn(&O) -> &A, G: Fn(&O) -> &B> SelfMonadZip<O, A, B, F, G> {
+    pub fn new(owner: O, first_func: F, second_func: G) -> Self {
+        SelfMonadZip {
+            owner,
+            first_func,
+            second_func,
+            first: PhantomData,
+            second: PhantomData
+        }
+    }
+
+    /// Runs both projections and returns their values as a pair.
+    pub fn get(&self) -> (&A, &B) {
+        ((self.first_func)(&self.owner), (self.second_func)(&self.owner))
+    }
+}
+
+/// Pairs two projections of one owner. The same as `SelfMonadZip::new`.
+pub fn zip<O, A, B, F, G>(owner: O, f: F, g: G) -> SelfMonadZip<O, A, B, F, G>
+where
+    A: ?Sized,
+    B: ?Sized,
+    F: Fn(&O) -> &A,
+    G: Fn(&O) -> &B
+{
+    SelfMonadZip::new(owner, f, g)
+}
+
+impl<O, A: ?Sized, B: ?Sized, F, G> SelfMonadOwner<O> for SelfMonadZip<O, A, B, F, G> {
+    fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    fn owner_mut(&mut self) -> &mut O {
+        &mut self.owner
+    }
+
+    fn owner_into(self) -> O {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{zip, SelfMonadOwner};
+
+    struct Person {
+        name: String,
+        tags: Vec<String>
+    }
+
+    #[test]
+    fn zip_fields() {
+        let person = Person { name: String::from("Ann"), tags: vec![String::from("admin")] };
+        let mut m = zip(person, |p| p.name.as_str(), |p| &p.tags[..]);
+        assert_eq!(("Ann", &[String::from("admin")][..]), m.get());
+
+        m.owner_mut().name.push('a');
+        let (name, tags) = m.get();
+        assert_eq!("Anna", name);
+        assert_eq!(1, tags.len());
+    }
+}